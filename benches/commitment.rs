@@ -1,5 +1,9 @@
 use divan::Bencher;
-use frivail::{friveil::FriVeilDefault, poly::Utils};
+use frivail::{
+    friveil::{B128, FriVeilDefault},
+    poly::{PackedMLE, Utils},
+    traits::{FriVeilSampling, FriVeilUtils},
+};
 #[cfg(feature = "kzg")]
 use kate::{
     M1NoPrecomp,
@@ -19,6 +23,11 @@ const DATA_14_MB: usize = 14 * 1024 * 1024;
 const DATA_16_MB: usize = 16 * 1024 * 1024;
 const DATA_32_MIB: usize = 32 * 1024 * 1024;
 
+/// Number of MLE variables for a blob of `bytes` bytes (16-byte B128 elements, 7 bit variables each)
+fn total_n_vars_for(bytes: usize) -> usize {
+    (bytes / 16).ilog2() as usize + 7
+}
+
 // A simple opinionated function to build KZG commitments over the data
 #[cfg(feature = "kzg")]
 pub fn kzg_commitment(data: &[u8], with_redundancy: bool, srs: &M1NoPrecomp) -> Vec<u8> {
@@ -213,13 +222,10 @@ fn fri_commitment_16mb(bencher: Bencher) {
 // FRI Proof Calculation Benchmarks (excluding evaluation point/claim time)
 #[divan::bench(max_time = 10)]
 fn fri_proof_4mb(bencher: Bencher) {
-    let mut rng = rand::rng();
-    let random_data: Vec<u8> = (0..DATA_4_MB).map(|_| rng.random()).collect();
-
     // Pre-compute setup outside the benchmark loop
     let packed_mle_values = Utils::new()
-        .bytes_to_packed_mle(&random_data)
-        .expect("Data should be convertible to packed MLE values");
+        .random_packed_mle(total_n_vars_for(DATA_4_MB), 0)
+        .expect("Random packed MLE should be generated");
     let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
     let (fri_params, ntt) = friveil
         .initialize_fri_context(packed_mle_values.packed_mle.log_len())
@@ -250,13 +256,10 @@ fn fri_proof_4mb(bencher: Bencher) {
 
 #[divan::bench(max_time = 10)]
 fn fri_proof_8mb(bencher: Bencher) {
-    let mut rng = rand::rng();
-    let random_data: Vec<u8> = (0..DATA_8_MB).map(|_| rng.random()).collect();
-
     // Pre-compute setup outside the benchmark loop
     let packed_mle_values = Utils::new()
-        .bytes_to_packed_mle(&random_data)
-        .expect("Data should be convertible to packed MLE values");
+        .random_packed_mle(total_n_vars_for(DATA_8_MB), 0)
+        .expect("Random packed MLE should be generated");
     let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
     let (fri_params, ntt) = friveil
         .initialize_fri_context(packed_mle_values.packed_mle.log_len())
@@ -287,13 +290,10 @@ fn fri_proof_8mb(bencher: Bencher) {
 
 #[divan::bench(max_time = 10)]
 fn fri_proof_16mb(bencher: Bencher) {
-    let mut rng = rand::rng();
-    let random_data: Vec<u8> = (0..DATA_16_MB).map(|_| rng.random()).collect();
-
     // Pre-compute setup outside the benchmark loop
     let packed_mle_values = Utils::new()
-        .bytes_to_packed_mle(&random_data)
-        .expect("Data should be convertible to packed MLE values");
+        .random_packed_mle(total_n_vars_for(DATA_16_MB), 0)
+        .expect("Random packed MLE should be generated");
     let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
     let (fri_params, ntt) = friveil
         .initialize_fri_context(packed_mle_values.packed_mle.log_len())
@@ -324,13 +324,10 @@ fn fri_proof_16mb(bencher: Bencher) {
 
 #[divan::bench(max_time = 10)]
 fn fri_proof_32mb(bencher: Bencher) {
-    let mut rng = rand::rng();
-    let random_data: Vec<u8> = (0..DATA_32_MIB).map(|_| rng.random()).collect();
-
     // Pre-compute setup outside the benchmark loop
     let packed_mle_values = Utils::new()
-        .bytes_to_packed_mle(&random_data)
-        .expect("Data should be convertible to packed MLE values");
+        .random_packed_mle(total_n_vars_for(DATA_32_MIB), 0)
+        .expect("Random packed MLE should be generated");
     let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
     let (fri_params, ntt) = friveil
         .initialize_fri_context(packed_mle_values.packed_mle.log_len())
@@ -358,3 +355,213 @@ fn fri_proof_32mb(bencher: Bencher) {
             .expect("Proof should be created successfully");
     });
 }
+
+// Sparse vs dense commitment of a 90%-zero blob (only the first 10% carries data)
+fn sparse_blob(size: usize) -> Vec<u8> {
+    let mut rng = rand::rng();
+    let mut data = vec![0u8; size];
+    data[..size / 10]
+        .iter_mut()
+        .for_each(|byte| *byte = rng.random());
+    data
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_commitment_4mb_90pct_zero_dense(bencher: Bencher) {
+    let data = sparse_blob(DATA_4_MB);
+
+    bencher.bench_local(|| {
+        let packed_mle_values = Utils::new()
+            .bytes_to_packed_mle(&data)
+            .expect("Data should be convertible to packed MLE values");
+        let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("FRI context should initialize successfully");
+        let _ = friveil
+            .commit(packed_mle_values.packed_mle, fri_params, &ntt)
+            .expect("Commitment should be created successfully");
+    });
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_commitment_4mb_90pct_zero_sparse(bencher: Bencher) {
+    let data = sparse_blob(DATA_4_MB);
+    let packed_mle_values = Utils::new()
+        .bytes_to_packed_mle(&data)
+        .expect("Data should be convertible to packed MLE values");
+    let nonzero: Vec<_> = packed_mle_values
+        .packed_values
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, value)| *value != Default::default())
+        .collect();
+    let total_n_vars = packed_mle_values.total_n_vars;
+    let packed_log_len = packed_mle_values.packed_mle.log_len();
+
+    bencher.bench_local(|| {
+        let friveil = FriVeilDefault::new(1, 128, total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_log_len)
+            .expect("FRI context should initialize successfully");
+        let _ = friveil
+            .commit_sparse(&nonzero, total_n_vars, fri_params, &ntt)
+            .expect("Commitment should be created successfully");
+    });
+}
+
+// Share proof extraction for dispersal: one opening per codeword position of a 1 MiB blob
+#[divan::bench(max_time = 10)]
+fn fri_share_proofs_1mb(bencher: Bencher) {
+    let packed_mle_values = Utils::new()
+        .random_packed_mle(total_n_vars_for(1024 * 1024), 0)
+        .expect("Random packed MLE should be generated");
+    let friveil = FriVeilDefault::new(1, 128, packed_mle_values.total_n_vars, 3);
+    let (fri_params, ntt) = friveil
+        .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+        .expect("FRI context should initialize successfully");
+    let commit_output = friveil
+        .commit(packed_mle_values.packed_mle, fri_params, &ntt)
+        .expect("Commitment should be created successfully");
+    let indices: Vec<usize> = (0..commit_output.codeword.len()).collect();
+
+    bencher.bench_local(|| {
+        let _ = friveil
+            .share_proofs(&commit_output, &indices)
+            .expect("Share proofs should be extracted successfully");
+    });
+}
+
+// Committing a slot's worth of 1 MiB blobs: a fresh FRI context per blob versus one
+// context shared by `commit_batch`
+const BATCH_BLOBS: usize = 8;
+
+fn batch_blob_mles() -> Vec<PackedMLE<B128>> {
+    (0..BATCH_BLOBS as u64)
+        .map(|seed| {
+            Utils::new()
+                .random_packed_mle(total_n_vars_for(1024 * 1024), seed)
+                .expect("Random packed MLE should be generated")
+        })
+        .collect()
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_commit_8x1mb_sequential(bencher: Bencher) {
+    let mles = batch_blob_mles();
+    let friveil = FriVeilDefault::new(1, 128, mles[0].total_n_vars, 3);
+
+    bencher.bench_local(|| {
+        for mle in &mles {
+            let (fri_params, ntt) = friveil
+                .initialize_fri_context(mle.packed_mle.log_len())
+                .expect("FRI context should initialize successfully");
+            let _ = friveil
+                .commit(mle.packed_mle.clone(), fri_params, &ntt)
+                .expect("Commitment should be created successfully");
+        }
+    });
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_commit_8x1mb_batch(bencher: Bencher) {
+    let mles = batch_blob_mles();
+    let friveil = FriVeilDefault::new(1, 128, mles[0].total_n_vars, 3);
+
+    bencher.bench_local(|| {
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(mles[0].packed_mle.log_len())
+            .expect("FRI context should initialize successfully");
+        let _ = friveil
+            .commit_batch(&mles, &fri_params, &ntt)
+            .expect("Batch commitment should be created successfully");
+    });
+}
+
+// Verification time by position of the first corrupted query, to size the early-abort
+// timing difference. Query positions come from Fiat-Shamir, but the query openings are
+// the tail of the proof, one fixed-size opening per query, so flipping the first byte of
+// that tail breaks the first query checked and flipping the last byte breaks the last.
+#[derive(Clone, Copy)]
+enum Corruption {
+    None,
+    FirstQuery,
+    LastQuery,
+}
+
+fn bench_verify_corrupted(bencher: Bencher, corruption: Corruption) {
+    const NUM_TEST_QUERIES: usize = 128;
+
+    let packed_mle_values = Utils::new()
+        .random_packed_mle(total_n_vars_for(DATA_4_MB), 0)
+        .expect("Random packed MLE should be generated");
+    let prove_with_queries = |num_test_queries: usize| {
+        let friveil = FriVeilDefault::new(1, num_test_queries, packed_mle_values.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("FRI context should initialize successfully");
+        let commit_output = friveil
+            .commit(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+            )
+            .expect("Commitment should be created successfully");
+        // Both proofs must be made at the same point for their length difference to be
+        // the size of one query opening
+        let evaluation_point = friveil
+            .calculate_evaluation_point_from_seed([0; 32])
+            .expect("Failed to generate evaluation point");
+        let proof = friveil
+            .prove_to_bytes(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+                &commit_output,
+                &evaluation_point,
+            )
+            .expect("Proof should be created successfully");
+        (friveil, fri_params, evaluation_point, proof)
+    };
+
+    let (friveil, fri_params, evaluation_point, mut proof) = prove_with_queries(NUM_TEST_QUERIES);
+    let query_bytes = prove_with_queries(NUM_TEST_QUERIES + 1).3.len() - proof.len();
+    let evaluation_claim = friveil
+        .calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point)
+        .expect("Failed to calculate evaluation claim");
+
+    let proof_len = proof.len();
+    match corruption {
+        Corruption::None => {}
+        Corruption::FirstQuery => proof[proof_len - NUM_TEST_QUERIES * query_bytes] ^= 1,
+        Corruption::LastQuery => proof[proof_len - 1] ^= 1,
+    }
+
+    bencher
+        .with_inputs(|| friveil.reconstruct_transcript_from_bytes(proof.clone()))
+        .bench_local_values(|mut verifier_transcript| {
+            let result = friveil.verify_evaluation(
+                &mut verifier_transcript,
+                evaluation_claim,
+                &evaluation_point,
+                &fri_params,
+            );
+            assert_eq!(result.is_ok(), matches!(corruption, Corruption::None));
+        });
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_verify_4mb_valid(bencher: Bencher) {
+    bench_verify_corrupted(bencher, Corruption::None);
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_verify_4mb_first_query_corrupted(bencher: Bencher) {
+    bench_verify_corrupted(bencher, Corruption::FirstQuery);
+}
+
+#[divan::bench(max_time = 10)]
+fn fri_verify_4mb_last_query_corrupted(bencher: Bencher) {
+    bench_verify_corrupted(bencher, Corruption::LastQuery);
+}
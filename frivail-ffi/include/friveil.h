@@ -0,0 +1,44 @@
+/*
+ * C interface to FRIVeil, built with:
+ *
+ *   cargo build --release -p frivail-ffi
+ *
+ * All functions return FRIVEIL_OK on success or a negative FRIVEIL_ERR_* code.
+ * Panics inside the library are caught and reported as FRIVEIL_ERR_PANIC.
+ */
+#ifndef FRIVEIL_H
+#define FRIVEIL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define FRIVEIL_OK 0
+#define FRIVEIL_ERR_NULL_POINTER -1
+#define FRIVEIL_ERR_INVALID_INPUT -2
+#define FRIVEIL_ERR_VERIFICATION_FAILED -3
+#define FRIVEIL_ERR_INTERNAL -4
+#define FRIVEIL_ERR_PANIC -5
+
+/* Opaque commitment handle, released with friveil_free. */
+typedef struct FriVeilHandle FriVeilHandle;
+
+/* Commit to data; writes the handle, the 32-byte root and the MLE variable count. */
+int32_t friveil_commit(const uint8_t *data, size_t data_len, size_t log_inv_rate,
+                       size_t num_test_queries, FriVeilHandle **out_handle,
+                       uint8_t out_root[32], size_t *out_n_vars);
+
+/* Prove at the default point; writes the 16-byte claim and a proof buffer
+ * released with friveil_free_bytes. */
+int32_t friveil_prove(const FriVeilHandle *handle, uint8_t out_claim[16],
+                      uint8_t **out_proof, size_t *out_proof_len);
+
+/* Verify a proof against a root; FRIVEIL_ERR_VERIFICATION_FAILED if invalid. */
+int32_t friveil_verify(size_t log_inv_rate, size_t num_test_queries, size_t n_vars,
+                       const uint8_t root[32], const uint8_t claim[16],
+                       const uint8_t *proof, size_t proof_len);
+
+void friveil_free(FriVeilHandle *handle);
+
+void friveil_free_bytes(uint8_t *bytes, size_t len);
+
+#endif /* FRIVEIL_H */
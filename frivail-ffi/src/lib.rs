@@ -0,0 +1,409 @@
+//! C ABI for FFI consumers
+//!
+//! Exposes commit, prove and verify over opaque handles and byte buffers, with integer
+//! status codes instead of Rust errors. The matching declarations are in
+//! `include/friveil.h`. This crate is a `cdylib` of its own, so Rust users of `frivail`
+//! don't build a shared library they never link; build it with:
+//!
+//! ```sh
+//! cargo build --release -p frivail-ffi
+//! ```
+//!
+//! No panic crosses the C boundary: each entry point catches unwinding panics and
+//! returns [`FRIVEIL_ERR_PANIC`] instead. That relies on the default `panic = "unwind"`;
+//! a build with `panic = "abort"` still aborts the host process.
+//!
+//! Proofs are always generated at the deterministic point returned by
+//! `calculate_evaluation_point_from_seed` for `EVALUATION_POINT_SEED`, so a verifier
+//! only needs the parameters, the commitment root, the proof bytes and the claim.
+
+use binius_field::ExtensionField;
+use binius_math::ntt::{NeighborsLastMultiThread, domain_context::GenericPreExpanded};
+use binius_verifier::{config::B1, fri::FRIParams};
+use frivail::{
+    error::FriVeilError,
+    friveil::{B128, FriVeilDefault},
+    poly::{PackedMLE, Utils},
+    store::StoredCommitOutput,
+    traits::{FriVeilSampling, FriVeilUtils},
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+/// Call succeeded
+pub const FRIVEIL_OK: i32 = 0;
+/// A required pointer argument was null
+pub const FRIVEIL_ERR_NULL_POINTER: i32 = -1;
+/// Parameters or input data were rejected
+pub const FRIVEIL_ERR_INVALID_INPUT: i32 = -2;
+/// The proof did not verify
+pub const FRIVEIL_ERR_VERIFICATION_FAILED: i32 = -3;
+/// An internal binius routine or I/O operation failed on otherwise valid input
+pub const FRIVEIL_ERR_INTERNAL: i32 = -4;
+/// A panic was caught before it could unwind into the caller
+pub const FRIVEIL_ERR_PANIC: i32 = -5;
+
+/// Log of the number of NTT shares used for every FFI context
+const LOG_NUM_SHARES: usize = 3;
+
+/// Seed of the evaluation point every FFI proof opens at
+const EVALUATION_POINT_SEED: [u8; 32] = [0; 32];
+
+/// Opaque commitment handle returned by [`friveil_commit`]
+///
+/// Holds everything needed to prove against the commitment. Release it with
+/// [`friveil_free`].
+pub struct FriVeilHandle {
+    friveil: FriVeilDefault,
+    packed_mle: PackedMLE<B128>,
+    fri_params: FRIParams<B128>,
+    ntt: NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    commit_output: StoredCommitOutput,
+}
+
+/// Status code reported for `error`
+fn error_code(error: &FriVeilError) -> i32 {
+    match error {
+        FriVeilError::Binius(_) | FriVeilError::Io(_) => FRIVEIL_ERR_INTERNAL,
+        _ => FRIVEIL_ERR_INVALID_INPUT,
+    }
+}
+
+/// Run an entry point's body, turning a panic into [`FRIVEIL_ERR_PANIC`]
+///
+/// The bodies only touch caller-owned buffers through raw pointers and handles that are
+/// either fully built or not returned, so nothing is observed in a broken state after
+/// unwinding, which is what makes asserting unwind safety sound.
+fn catch_panic(body: impl FnOnce() -> i32) -> i32 {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(FRIVEIL_ERR_PANIC)
+}
+
+/// Borrow `len` bytes at `ptr`, treating a null pointer as empty only when `len` is zero
+///
+/// # Safety
+///
+/// A non-null `ptr` must be valid for reads of `len` bytes.
+unsafe fn input_bytes<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() {
+        return (len == 0).then_some(&[][..]);
+    }
+    // SAFETY: the caller guarantees `ptr` is valid for `len` bytes
+    Some(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// Commit to `data_len` bytes at `data`
+///
+/// On success writes a new handle to `*out_handle`, the 32-byte root to `out_root` and
+/// the number of MLE variables (needed by [`friveil_verify`]) to `*out_n_vars`.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `data_len` bytes, `out_root` for writes of 32 bytes,
+/// and `out_handle` and `out_n_vars` for one write each.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn friveil_commit(
+    data: *const u8,
+    data_len: usize,
+    log_inv_rate: usize,
+    num_test_queries: usize,
+    out_handle: *mut *mut FriVeilHandle,
+    out_root: *mut u8,
+    out_n_vars: *mut usize,
+) -> i32 {
+    catch_panic(|| {
+        if out_handle.is_null() || out_root.is_null() || out_n_vars.is_null() {
+            return FRIVEIL_ERR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees `data` is valid for `data_len` bytes
+        let Some(data) = (unsafe { input_bytes(data, data_len) }) else {
+            return FRIVEIL_ERR_NULL_POINTER;
+        };
+
+        let commit = || -> Result<FriVeilHandle, FriVeilError> {
+            let packed_mle = Utils::<B128>::new().bytes_to_packed_mle(data)?;
+            let friveil = FriVeilDefault::new(
+                log_inv_rate,
+                num_test_queries,
+                packed_mle.total_n_vars,
+                LOG_NUM_SHARES,
+            );
+            let (fri_params, ntt) =
+                friveil.initialize_fri_context(packed_mle.packed_mle.log_len())?;
+            let commit_output =
+                friveil.commit(packed_mle.packed_mle.clone(), fri_params.clone(), &ntt)?;
+            Ok(FriVeilHandle {
+                friveil,
+                packed_mle,
+                fri_params,
+                ntt,
+                commit_output,
+            })
+        };
+        let handle = match commit() {
+            Ok(handle) => handle,
+            Err(error) => return error_code(&error),
+        };
+
+        // SAFETY: output pointers are non-null and valid per the caller contract
+        unsafe {
+            std::ptr::copy_nonoverlapping(handle.commit_output.commitment.as_ptr(), out_root, 32);
+            *out_n_vars = handle.packed_mle.total_n_vars;
+            *out_handle = Box::into_raw(Box::new(handle));
+        }
+        FRIVEIL_OK
+    })
+}
+
+/// Prove the committed data's evaluation at the default point
+///
+/// On success writes the 16-byte little-endian claim to `out_claim` and a newly
+/// allocated proof buffer to `*out_proof` / `*out_proof_len`. Release the buffer with
+/// [`friveil_free_bytes`].
+///
+/// # Safety
+///
+/// `handle` must come from [`friveil_commit`] and not have been freed, `out_claim` must be
+/// valid for writes of 16 bytes, and `out_proof` and `out_proof_len` for one write each.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn friveil_prove(
+    handle: *const FriVeilHandle,
+    out_claim: *mut u8,
+    out_proof: *mut *mut u8,
+    out_proof_len: *mut usize,
+) -> i32 {
+    catch_panic(|| {
+        if handle.is_null() || out_claim.is_null() || out_proof.is_null() || out_proof_len.is_null()
+        {
+            return FRIVEIL_ERR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees `handle` is a live handle from `friveil_commit`
+        let handle = unsafe { &*handle };
+
+        let prove = || -> Result<(B128, Vec<u8>), FriVeilError> {
+            let evaluation_point = handle
+                .friveil
+                .calculate_evaluation_point_from_seed(EVALUATION_POINT_SEED)?;
+            let evaluation_claim = handle
+                .friveil
+                .calculate_evaluation_claim(&handle.packed_mle.packed_values, &evaluation_point)?;
+            let proof = handle.friveil.prove_to_bytes(
+                handle.packed_mle.packed_mle.clone(),
+                handle.fri_params.clone(),
+                &handle.ntt,
+                &handle.commit_output,
+                &evaluation_point,
+            )?;
+            Ok((evaluation_claim, proof))
+        };
+        let (evaluation_claim, proof) = match prove() {
+            Ok(output) => output,
+            Err(error) => return error_code(&error),
+        };
+
+        let proof = proof.into_boxed_slice();
+        // SAFETY: output pointers are non-null and valid per the caller contract
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                u128::from(evaluation_claim).to_le_bytes().as_ptr(),
+                out_claim,
+                16,
+            );
+            *out_proof_len = proof.len();
+            *out_proof = Box::into_raw(proof).cast::<u8>();
+        }
+        FRIVEIL_OK
+    })
+}
+
+/// Verify a proof from [`friveil_prove`] against a commitment root
+///
+/// `log_inv_rate`, `num_test_queries` and `n_vars` must match the values the commitment
+/// was made with. Returns [`FRIVEIL_OK`] for a valid proof and
+/// [`FRIVEIL_ERR_VERIFICATION_FAILED`] otherwise.
+///
+/// # Safety
+///
+/// `root` must be valid for reads of 32 bytes, `claim` of 16 bytes, and `proof` of
+/// `proof_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn friveil_verify(
+    log_inv_rate: usize,
+    num_test_queries: usize,
+    n_vars: usize,
+    root: *const u8,
+    claim: *const u8,
+    proof: *const u8,
+    proof_len: usize,
+) -> i32 {
+    catch_panic(|| {
+        if root.is_null() || claim.is_null() {
+            return FRIVEIL_ERR_NULL_POINTER;
+        }
+        // SAFETY: the caller guarantees the lengths of all three buffers
+        let (root, claim, proof) = unsafe {
+            (
+                *root.cast::<[u8; 32]>(),
+                *claim.cast::<[u8; 16]>(),
+                input_bytes(proof, proof_len),
+            )
+        };
+        let Some(proof) = proof else {
+            return FRIVEIL_ERR_NULL_POINTER;
+        };
+
+        let friveil = FriVeilDefault::new(log_inv_rate, num_test_queries, n_vars, LOG_NUM_SHARES);
+        let Some(packed_log_len) = n_vars.checked_sub(<B128 as ExtensionField<B1>>::LOG_DEGREE)
+        else {
+            return FRIVEIL_ERR_INVALID_INPUT;
+        };
+        let fri_params = match friveil.fri_params_for(packed_log_len) {
+            Ok(fri_params) => fri_params,
+            Err(error) => return error_code(&error),
+        };
+        let evaluation_point =
+            match friveil.calculate_evaluation_point_from_seed(EVALUATION_POINT_SEED) {
+                Ok(evaluation_point) => evaluation_point,
+                Err(error) => return error_code(&error),
+            };
+
+        let mut verifier_transcript = friveil.reconstruct_transcript_from_bytes(proof.to_vec());
+        match friveil.verify_evaluation_with_root(
+            &mut verifier_transcript,
+            root,
+            B128::from(u128::from_le_bytes(claim)),
+            &evaluation_point,
+            &fri_params,
+        ) {
+            Ok(()) => FRIVEIL_OK,
+            Err(_) => FRIVEIL_ERR_VERIFICATION_FAILED,
+        }
+    })
+}
+
+/// Release a handle returned by [`friveil_commit`]; null is ignored
+///
+/// # Safety
+///
+/// `handle` must be null or come from [`friveil_commit`] and not have been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn friveil_free(handle: *mut FriVeilHandle) {
+    catch_panic(|| {
+        if !handle.is_null() {
+            // SAFETY: the handle was created by `Box::into_raw` in `friveil_commit`
+            drop(unsafe { Box::from_raw(handle) });
+        }
+        FRIVEIL_OK
+    });
+}
+
+/// Release a proof buffer returned by [`friveil_prove`]; null is ignored
+///
+/// # Safety
+///
+/// `bytes` and `len` must be exactly the pointer and length written by
+/// [`friveil_prove`], not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn friveil_free_bytes(bytes: *mut u8, len: usize) {
+    catch_panic(|| {
+        if !bytes.is_null() {
+            // SAFETY: the buffer was created by `Box::into_raw` on a boxed slice of `len` bytes
+            drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(bytes, len)) });
+        }
+        FRIVEIL_OK
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_ffi_round_trip() {
+        let data: Vec<u8> = (0..1024).map(|i| (i % 256) as u8).collect();
+        let mut handle = ptr::null_mut();
+        let mut root = [0u8; 32];
+        let mut n_vars = 0usize;
+        let mut claim = [0u8; 16];
+        let mut proof = ptr::null_mut();
+        let mut proof_len = 0usize;
+
+        unsafe {
+            assert_eq!(
+                friveil_commit(
+                    data.as_ptr(),
+                    data.len(),
+                    1,
+                    3,
+                    &mut handle,
+                    root.as_mut_ptr(),
+                    &mut n_vars,
+                ),
+                FRIVEIL_OK
+            );
+            assert_eq!(
+                friveil_prove(handle, claim.as_mut_ptr(), &mut proof, &mut proof_len),
+                FRIVEIL_OK
+            );
+
+            let verify = |root: &[u8; 32]| {
+                friveil_verify(
+                    1,
+                    3,
+                    n_vars,
+                    root.as_ptr(),
+                    claim.as_ptr(),
+                    proof,
+                    proof_len,
+                )
+            };
+            assert_eq!(verify(&root), FRIVEIL_OK);
+            let mut other_root = root;
+            other_root[0] ^= 1;
+            assert_eq!(verify(&other_root), FRIVEIL_ERR_VERIFICATION_FAILED);
+
+            assert_eq!(
+                friveil_prove(ptr::null(), claim.as_mut_ptr(), &mut proof, &mut proof_len),
+                FRIVEIL_ERR_NULL_POINTER
+            );
+
+            friveil_free_bytes(proof, proof_len);
+            friveil_free(handle);
+        }
+    }
+
+    #[test]
+    fn test_ffi_status_codes() {
+        assert_eq!(catch_panic(|| panic!("boom")), FRIVEIL_ERR_PANIC);
+        assert_eq!(catch_panic(|| FRIVEIL_OK), FRIVEIL_OK);
+
+        assert_eq!(
+            error_code(&FriVeilError::Binius("encode failed".to_string())),
+            FRIVEIL_ERR_INTERNAL
+        );
+        assert_eq!(
+            error_code(&FriVeilError::EmptyInput),
+            FRIVEIL_ERR_INVALID_INPUT
+        );
+
+        // Empty data is rejected as invalid input, not flattened with internal failures
+        let mut handle = ptr::null_mut();
+        let mut root = [0u8; 32];
+        let mut n_vars = 0usize;
+        let status = unsafe {
+            friveil_commit(
+                ptr::null(),
+                0,
+                1,
+                3,
+                &mut handle,
+                root.as_mut_ptr(),
+                &mut n_vars,
+            )
+        };
+        assert_eq!(status, FRIVEIL_ERR_INVALID_INPUT);
+        assert!(handle.is_null());
+    }
+}
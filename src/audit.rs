@@ -0,0 +1,146 @@
+//! Self-describing record of one commit+prove run for third-party audit
+//!
+//! An [`AuditRecord`] holds everything needed to replay verification independently: a
+//! hash of the input data, the parameters, the evaluation point and claim, the
+//! commitment root and the proof. Its [`AuditRecord::record_digest`] hashes all of
+//! these so the record can be signed or pinned as a whole. With the `serde` feature it
+//! derives `Serialize`/`Deserialize` for export as JSON or any other serde format.
+//!
+//! The digest is plain SHA-256 with no key: anyone who edits a record can recompute
+//! it. On its own it only catches accidental corruption; it is evidence against
+//! tampering once the prover signs it, or pins it somewhere the auditor trusts.
+//! Likewise the recorded parameters are never trusted: [`AuditRecord::replay`]
+//! verifies under the auditor's own parameters and rejects a record declaring others.
+
+use crate::{
+    error::FriVeilError,
+    friveil::{B128, FriVeilDefault, check_declared_params},
+    traits::{FriVeilSampling, FriVeilUtils},
+};
+use binius_verifier::fri::FRIParams;
+use sha2::{Digest, Sha256};
+
+/// Share count for the replay context; verification never encodes, so it has no effect
+const REPLAY_LOG_NUM_SHARES: usize = 3;
+
+/// Inputs and outputs of a commit+prove run, sufficient to re-verify it
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditRecord {
+    /// SHA-256 of the committed input bytes
+    pub data_hash: [u8; 32],
+    /// Logarithm of the Reed-Solomon inverse rate
+    pub log_inv_rate: usize,
+    /// Number of FRI test queries
+    pub num_test_queries: usize,
+    /// Number of variables of the committed MLE
+    pub n_vars: usize,
+    /// Evaluation point, one little-endian `u128` per coordinate
+    pub evaluation_point: Vec<u128>,
+    /// Claimed evaluation at `evaluation_point`
+    pub evaluation_claim: u128,
+    /// Commitment root
+    pub commitment: [u8; 32],
+    /// Serialized evaluation proof
+    pub proof: Vec<u8>,
+    /// Unkeyed digest over every field above, see [`AuditRecord::compute_digest`]; sign
+    /// it externally for it to prove the record wasn't modified
+    pub record_digest: [u8; 32],
+}
+
+impl AuditRecord {
+    /// SHA-256 over the record's fields in declaration order, with lengths prefixed
+    #[must_use]
+    pub fn compute_digest(&self) -> [u8; 32] {
+        let point = self
+            .evaluation_point
+            .iter()
+            .flat_map(|coordinate| coordinate.to_le_bytes())
+            .collect::<Vec<u8>>();
+        Sha256::new()
+            .chain_update(b"friveil-audit-record")
+            .chain_update(self.data_hash)
+            .chain_update((self.log_inv_rate as u64).to_le_bytes())
+            .chain_update((self.num_test_queries as u64).to_le_bytes())
+            .chain_update((self.n_vars as u64).to_le_bytes())
+            .chain_update((self.evaluation_point.len() as u64).to_le_bytes())
+            .chain_update(point)
+            .chain_update(self.evaluation_claim.to_le_bytes())
+            .chain_update(self.commitment)
+            .chain_update((self.proof.len() as u64).to_le_bytes())
+            .chain_update(&self.proof)
+            .finalize()
+            .into()
+    }
+
+    /// Check the record hashes `data`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `data` is the committed input
+    /// * `Err(FriVeilError)` - Data hash mismatch
+    pub fn check_data(&self, data: &[u8]) -> Result<(), FriVeilError> {
+        let data_hash: [u8; 32] = Sha256::digest(data).into();
+        if data_hash != self.data_hash {
+            return Err(FriVeilError::CommitmentMismatch(
+                "Audit data mismatch: data does not hash to the recorded value".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Re-verify the recorded proof under the auditor's parameters
+    ///
+    /// Checks [`Self::record_digest`], checks the recorded parameters equal
+    /// `fri_params`, then verifies the proof against the recorded commitment, point and
+    /// claim. Nothing from the prover is needed beyond the record.
+    ///
+    /// A matching digest only shows the record is internally consistent, since anyone
+    /// can recompute it; check a signature over it first if the record came from an
+    /// untrusted party.
+    ///
+    /// # Arguments
+    ///
+    /// * `fri_params` - FRI parameters the auditor expects the proof to meet
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The record is consistent and its proof verifies under `fri_params`
+    /// * `Err(FriVeilError)` - Corrupted record, parameters other than `fri_params`, or
+    ///   the proof is invalid
+    pub fn replay(&self, fri_params: &FRIParams<B128>) -> Result<(), FriVeilError> {
+        if self.compute_digest() != self.record_digest {
+            return Err(FriVeilError::CommitmentMismatch(
+                "Audit record digest mismatch: record was modified".to_string(),
+            ));
+        }
+        check_declared_params(
+            self.log_inv_rate,
+            self.num_test_queries,
+            self.n_vars,
+            fri_params,
+        )?;
+
+        let friveil = FriVeilDefault::new(
+            self.log_inv_rate,
+            self.num_test_queries,
+            self.n_vars,
+            REPLAY_LOG_NUM_SHARES,
+        );
+        let evaluation_point: Vec<B128> = self
+            .evaluation_point
+            .iter()
+            .map(|&coordinate| B128::from(coordinate))
+            .collect();
+
+        let mut verifier_transcript = friveil
+            .reconstruct_transcript_bounded(&self.proof, friveil.max_proof_bytes(fri_params))?;
+        friveil.verify_evaluation_with_root(
+            &mut verifier_transcript,
+            self.commitment,
+            B128::from(self.evaluation_claim),
+            &evaluation_point,
+            fri_params,
+        )
+    }
+}
@@ -0,0 +1,373 @@
+//! Context a proof is bound to
+//!
+//! Some deployments need a proof to be valid only in a given context, e.g. the chain
+//! epoch the blob was published in. `prove_bound` writes the encoded [`ProofBinding`]
+//! into the transcript right after the [`ProofHeader`](crate::header::ProofHeader), so
+//! it is absorbed into Fiat-Shamir and every FRI challenge depends on it. The verifier
+//! reads it back and compares it against the binding it expects before any FRI work.
+//!
+//! Unbound proofs carry an empty binding, so a bound proof is rejected by a verifier
+//! that doesn't expect one, and vice versa.
+//!
+//! # Layout
+//!
+//! ```text
+//! | len: u16 LE | entry_0 | ... | entry_k |
+//! entry = | tag: u8 | value |
+//! ```
+//!
+//! Entries appear at most once each, in increasing tag order, so every binding has a
+//! single encoding.
+
+use crate::error::FriVeilError;
+
+/// Tag of the epoch entry, followed by the epoch as `u64` LE
+const EPOCH_TAG: u8 = 0x01;
+/// Tag of the data length entry, followed by the length in bytes as `u64` LE
+const DATA_LEN_TAG: u8 = 0x02;
+/// Tag of the nonce entry, followed by the 32 nonce bytes
+const NONCE_TAG: u8 = 0x03;
+/// Tag of the expiry entry, followed by the expiry timestamp as `u64` LE
+const VALID_UNTIL_TAG: u8 = 0x04;
+
+/// Upper bound on the encoded binding, checked before allocating on the verifier side
+pub const MAX_BINDING_LEN: usize = 256;
+
+/// Context values a proof is bound to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProofBinding {
+    /// Chain epoch or block height the proof is valid for
+    pub epoch: Option<u64>,
+    /// Byte length of the original data the committed MLE was packed from
+    pub data_len: Option<u64>,
+    /// Nonce the published commitment was randomized with, see
+    /// [`nonced_root`](crate::friveil::nonced_root)
+    pub nonce: Option<[u8; 32]>,
+    /// Last timestamp, in the deployment's time unit, at which the proof is valid
+    pub valid_until: Option<u64>,
+}
+
+impl ProofBinding {
+    /// Binding with no context, as written by plain `prove`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind to chain epoch `epoch`
+    #[must_use]
+    pub fn with_epoch(mut self, epoch: u64) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
+    /// Bind to a blob of `data_len` bytes, e.g. the input to `bytes_to_packed_mle`
+    ///
+    /// The length travels with the proof, so [`proof_metadata`] can report it without
+    /// external metadata, and a verifier expecting another blob size rejects the proof.
+    ///
+    /// [`proof_metadata`]: crate::friveil::proof_metadata
+    #[must_use]
+    pub fn with_data_len(mut self, data_len: u64) -> Self {
+        self.data_len = Some(data_len);
+        self
+    }
+
+    /// Bind to the nonce the published root was derived with
+    ///
+    /// Verifiers pass the same nonce to `verify_evaluation_with_nonce`, which expects it
+    /// here as well as in the nonced root.
+    #[must_use]
+    pub fn with_nonce(mut self, nonce: [u8; 32]) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Bind to an expiry timestamp, published alongside the commitment
+    ///
+    /// Verifiers check it against their current time with [`Self::check_fresh`], so an
+    /// availability proof cannot be replayed after the data may have been pruned. The
+    /// timestamp is only as trustworthy as the verifier's clock: a verifier whose clock
+    /// lags accepts expired proofs.
+    #[must_use]
+    pub fn with_valid_until(mut self, valid_until: u64) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Encode the binding entries, without the length prefix
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if let Some(epoch) = self.epoch {
+            bytes.push(EPOCH_TAG);
+            bytes.extend_from_slice(&epoch.to_le_bytes());
+        }
+        if let Some(data_len) = self.data_len {
+            bytes.push(DATA_LEN_TAG);
+            bytes.extend_from_slice(&data_len.to_le_bytes());
+        }
+        if let Some(nonce) = self.nonce {
+            bytes.push(NONCE_TAG);
+            bytes.extend_from_slice(&nonce);
+        }
+        if let Some(valid_until) = self.valid_until {
+            bytes.push(VALID_UNTIL_TAG);
+            bytes.extend_from_slice(&valid_until.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode binding entries produced by [`Self::to_bytes`]
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProofBinding)` - Decoded binding
+    /// * `Err(FriVeilError)` - Unknown, repeated or out-of-order tag, or truncated entry
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FriVeilError> {
+        let mut binding = Self::default();
+        let mut last_tag = 0u8;
+        let mut rest = bytes;
+        while let Some((&tag, tail)) = rest.split_first() {
+            if tag <= last_tag {
+                return Err(FriVeilError::FieldDecode(format!(
+                    "Binding tag {:#04x} repeated or out of order",
+                    tag
+                )));
+            }
+            last_tag = tag;
+            rest = match tag {
+                EPOCH_TAG => {
+                    let (value, tail) = split_value::<8>(tail, "epoch")?;
+                    binding.epoch = Some(u64::from_le_bytes(value));
+                    tail
+                }
+                DATA_LEN_TAG => {
+                    let (value, tail) = split_value::<8>(tail, "data length")?;
+                    binding.data_len = Some(u64::from_le_bytes(value));
+                    tail
+                }
+                NONCE_TAG => {
+                    let (value, tail) = split_value::<32>(tail, "nonce")?;
+                    binding.nonce = Some(value);
+                    tail
+                }
+                VALID_UNTIL_TAG => {
+                    let (value, tail) = split_value::<8>(tail, "expiry")?;
+                    binding.valid_until = Some(u64::from_le_bytes(value));
+                    tail
+                }
+                _ => {
+                    return Err(FriVeilError::FieldDecode(format!(
+                        "Unknown binding tag {:#04x}",
+                        tag
+                    )));
+                }
+            };
+        }
+        Ok(binding)
+    }
+
+    /// Check the binding read from a proof against the one the verifier expects
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Bindings match
+    /// * `Err(FriVeilError::BindingMismatch)` - Mismatch naming the differing context
+    pub fn check_against(&self, expected: &ProofBinding) -> Result<(), FriVeilError> {
+        if self.epoch != expected.epoch {
+            return Err(mismatch("epoch", expected.epoch, self.epoch));
+        }
+        if self.data_len != expected.data_len {
+            return Err(mismatch("data length", expected.data_len, self.data_len));
+        }
+        if self.nonce != expected.nonce {
+            return Err(FriVeilError::BindingMismatch {
+                field: "nonce",
+                expected: if expected.nonce.is_some() {
+                    "a nonce"
+                } else {
+                    "no nonce"
+                }
+                .to_string(),
+                got: if self.nonce.is_some() {
+                    "a different nonce"
+                } else {
+                    "no nonce"
+                }
+                .to_string(),
+            });
+        }
+        if self.valid_until != expected.valid_until {
+            return Err(mismatch("expiry", expected.valid_until, self.valid_until));
+        }
+        Ok(())
+    }
+
+    /// Check the binding has not expired at time `now`
+    ///
+    /// `now` comes from the verifier's own clock, in the same unit as
+    /// [`Self::valid_until`]; the check is only as trustworthy as that clock.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `now` is at or before the expiry
+    /// * `Err(FriVeilError::BindingMismatch)` - The binding has no expiry
+    /// * `Err(FriVeilError::Expired)` - `now` is past the expiry
+    pub fn check_fresh(&self, now: u64) -> Result<(), FriVeilError> {
+        match self.valid_until {
+            None => Err(FriVeilError::BindingMismatch {
+                field: "expiry",
+                expected: "an expiry to check freshness against".to_string(),
+                got: "no expiry".to_string(),
+            }),
+            Some(valid_until) if now > valid_until => {
+                Err(FriVeilError::Expired { valid_until, now })
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+fn split_value<'a, const N: usize>(
+    bytes: &'a [u8],
+    name: &str,
+) -> Result<([u8; N], &'a [u8]), FriVeilError> {
+    if bytes.len() < N {
+        return Err(FriVeilError::TranscriptTruncated(format!(
+            "Truncated binding entry {}",
+            name
+        )));
+    }
+    let (value, tail) = bytes.split_at(N);
+    Ok((value.try_into().expect("split at N"), tail))
+}
+
+fn mismatch(field: &'static str, expected: Option<u64>, got: Option<u64>) -> FriVeilError {
+    FriVeilError::BindingMismatch {
+        field,
+        expected: describe(field, expected),
+        got: describe(field, got),
+    }
+}
+
+fn describe(name: &str, value: Option<u64>) -> String {
+    value.map_or_else(
+        || format!("no {}", name),
+        |value| format!("{} {}", name, value),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proof_binding_round_trip() {
+        assert!(ProofBinding::new().to_bytes().is_empty());
+
+        let binding = ProofBinding::new().with_epoch(5);
+        let bytes = binding.to_bytes();
+        assert_eq!(bytes.len(), 9);
+        assert_eq!(ProofBinding::from_bytes(&bytes).unwrap(), binding);
+
+        assert!(ProofBinding::from_bytes(&bytes[..4]).is_err());
+        assert!(ProofBinding::from_bytes(&[0x7f]).is_err());
+        let repeated = [bytes.clone(), bytes].concat();
+        assert!(ProofBinding::from_bytes(&repeated).is_err());
+    }
+
+    #[test]
+    fn test_proof_binding_epoch_mismatch() {
+        let proof = ProofBinding::new().with_epoch(5);
+        assert!(
+            proof
+                .check_against(&ProofBinding::new().with_epoch(5))
+                .is_ok()
+        );
+
+        let err = proof
+            .check_against(&ProofBinding::new().with_epoch(6))
+            .expect_err("Epoch 5 proof should fail under epoch 6");
+        assert!(
+            matches!(err, FriVeilError::BindingMismatch { field: "epoch", .. }),
+            "{}",
+            err
+        );
+        assert!(proof.check_against(&ProofBinding::new()).is_err());
+    }
+
+    #[test]
+    fn test_proof_binding_data_len() {
+        let binding = ProofBinding::new().with_epoch(5).with_data_len(1000);
+        let decoded = ProofBinding::from_bytes(&binding.to_bytes()).unwrap();
+        assert_eq!(decoded.data_len, Some(1000));
+
+        let err = decoded
+            .check_against(&ProofBinding::new().with_epoch(5).with_data_len(1024))
+            .expect_err("Proof for a 1000-byte blob should fail for 1024 bytes");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::BindingMismatch {
+                    field: "data length",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_proof_binding_nonce() {
+        let binding = ProofBinding::new().with_data_len(64).with_nonce([9; 32]);
+        let bytes = binding.to_bytes();
+        assert_eq!(bytes.len(), 9 + 33);
+        assert_eq!(ProofBinding::from_bytes(&bytes).unwrap(), binding);
+
+        let err = binding
+            .check_against(&ProofBinding::new().with_data_len(64).with_nonce([8; 32]))
+            .expect_err("Different nonce should fail");
+        assert!(
+            matches!(err, FriVeilError::BindingMismatch { field: "nonce", .. }),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_proof_binding_valid_until() {
+        let binding = ProofBinding::new().with_epoch(5).with_valid_until(100);
+        let decoded = ProofBinding::from_bytes(&binding.to_bytes()).unwrap();
+        assert_eq!(decoded, binding);
+
+        assert!(decoded.check_fresh(100).is_ok());
+        let err = decoded
+            .check_fresh(101)
+            .expect_err("Binding should be expired after its expiry");
+        assert_eq!(
+            err,
+            FriVeilError::Expired {
+                valid_until: 100,
+                now: 101
+            }
+        );
+        assert!(ProofBinding::new().check_fresh(0).is_err());
+
+        let err = decoded
+            .check_against(&ProofBinding::new().with_epoch(5).with_valid_until(200))
+            .expect_err("Different expiry should fail");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::BindingMismatch {
+                    field: "expiry",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Self-contained container for a commitment and one evaluation proof
+//!
+//! A bundle carries everything a verifier needs besides the evaluation point and claim:
+//! the commitment root, the parameters it was proven under, and the serialized proof.
+//! `FriVeilUtils::bundle` and `FriVeilUtils::unbundle` convert to and from live
+//! prover/verifier types; `unbundle` checks the declared parameters against the
+//! verifier's own rather than trusting them.
+//!
+//! # Layout
+//!
+//! ```text
+//! | magic: "FVBN" | version: u8 | log_inv_rate: u8 | num_test_queries: u32 LE |
+//! | n_vars: u8 | root: [u8; 32] | proof_len: u64 LE | proof: [u8; proof_len] |
+//! ```
+//!
+//! The explicit proof length makes a truncated or extended bundle an error rather than
+//! a proof that fails deep inside FRI.
+
+use crate::error::FriVeilError;
+
+/// Magic bytes every bundle starts with
+pub const BUNDLE_MAGIC: [u8; 4] = *b"FVBN";
+
+/// Container format version written after the magic
+pub const BUNDLE_FORMAT_VERSION: u8 = 1;
+
+/// Size of everything before the proof bytes
+const BUNDLE_PREFIX_LEN: usize = 4 + 1 + 1 + 4 + 1 + 32 + 8;
+
+/// Decoded contents of a bundle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofBundle {
+    /// Logarithm of the Reed-Solomon inverse rate
+    pub log_inv_rate: u8,
+    /// Number of FRI test queries the proof answers
+    pub num_test_queries: u32,
+    /// Number of variables of the committed MLE
+    pub n_vars: u8,
+    /// Commitment root
+    pub root: [u8; 32],
+    /// Serialized proof transcript
+    pub proof: Vec<u8>,
+}
+
+impl ProofBundle {
+    /// Encode the bundle in its wire layout
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(BUNDLE_PREFIX_LEN + self.proof.len());
+        bytes.extend_from_slice(&BUNDLE_MAGIC);
+        bytes.push(BUNDLE_FORMAT_VERSION);
+        bytes.push(self.log_inv_rate);
+        bytes.extend_from_slice(&self.num_test_queries.to_le_bytes());
+        bytes.push(self.n_vars);
+        bytes.extend_from_slice(&self.root);
+        bytes.extend_from_slice(&(self.proof.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.proof);
+        bytes
+    }
+
+    /// Decode a bundle produced by [`Self::to_bytes`]
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProofBundle)` - Decoded bundle
+    /// * `Err(FriVeilError)` - Wrong magic, unsupported version, or truncated/trailing bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FriVeilError> {
+        let (prefix, proof) = bytes.split_at_checked(BUNDLE_PREFIX_LEN).ok_or_else(|| {
+            FriVeilError::TranscriptTruncated(format!(
+                "Truncated bundle: {} bytes is shorter than the {}-byte prefix",
+                bytes.len(),
+                BUNDLE_PREFIX_LEN
+            ))
+        })?;
+        if prefix[..4] != BUNDLE_MAGIC {
+            return Err(FriVeilError::FieldDecode(
+                "Not a proof bundle: magic mismatch".to_string(),
+            ));
+        }
+        if prefix[4] != BUNDLE_FORMAT_VERSION {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "bundle format version",
+                expected: BUNDLE_FORMAT_VERSION as usize,
+                got: prefix[4] as usize,
+            });
+        }
+
+        let proof_len = u64::from_le_bytes(prefix[43..51].try_into().expect("8 bytes"));
+        if proof.len() as u64 != proof_len {
+            return Err(FriVeilError::TranscriptTruncated(format!(
+                "Bundle proof length mismatch: header says {} bytes but {} follow",
+                proof_len,
+                proof.len()
+            )));
+        }
+
+        Ok(Self {
+            log_inv_rate: prefix[5],
+            num_test_queries: u32::from_le_bytes(prefix[6..10].try_into().expect("4 bytes")),
+            n_vars: prefix[10],
+            root: prefix[11..43].try_into().expect("32 bytes"),
+            proof: proof.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_bundle() -> ProofBundle {
+        ProofBundle {
+            log_inv_rate: 1,
+            num_test_queries: 128,
+            n_vars: 20,
+            root: [7; 32],
+            proof: (0..100).collect(),
+        }
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let bundle = sample_bundle();
+        let bytes = bundle.to_bytes();
+        assert_eq!(bytes.len(), BUNDLE_PREFIX_LEN + 100);
+        assert_eq!(ProofBundle::from_bytes(&bytes).unwrap(), bundle);
+    }
+
+    #[test]
+    fn test_bundle_rejects_truncation_and_corruption() {
+        let bytes = sample_bundle().to_bytes();
+
+        let err = ProofBundle::from_bytes(&bytes[..bytes.len() - 1])
+            .expect_err("Truncated proof should fail");
+        assert!(matches!(err, FriVeilError::TranscriptTruncated(_)));
+        let err = ProofBundle::from_bytes(&bytes[..20]).expect_err("Truncated prefix should fail");
+        assert!(matches!(err, FriVeilError::TranscriptTruncated(_)));
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = BUNDLE_FORMAT_VERSION + 1;
+        assert!(matches!(
+            ProofBundle::from_bytes(&wrong_version),
+            Err(FriVeilError::HeaderMismatch {
+                field: "bundle format version",
+                ..
+            })
+        ));
+        let mut wrong_magic = bytes;
+        wrong_magic[0] ^= 1;
+        assert!(matches!(
+            ProofBundle::from_bytes(&wrong_magic),
+            Err(FriVeilError::FieldDecode(_))
+        ));
+    }
+}
@@ -0,0 +1,428 @@
+//! In-memory caches for repeated proving and verification work
+//!
+//! A DA service that keeps proving the same commitment at recurring evaluation points
+//! (e.g. deterministic sampling rounds) can keep the resulting transcripts around
+//! instead of re-running the FRI prover, and a node serving the same proof to many
+//! clients can keep the verification result. Caches here are opt-in: nothing in
+//! [`FriVeil`](crate::friveil::FriVeil) consults them unless a `*_cached` method is used.
+
+use crate::error::FriVeilError;
+use binius_transcript::VerifierTranscript;
+use binius_verifier::{
+    config::{B128, StdChallenger},
+    fri::FRIParams,
+};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, hash::Hash};
+
+/// Cache key: commitment root, the exact evaluation point and the FRI parameters
+///
+/// The point is stored in full (16 little-endian bytes per coordinate) rather than as a
+/// digest, so two distinct points can never alias to the same cached proof. The
+/// parameters are part of the key because the same data commits to the same root under,
+/// e.g., different query counts, while the proofs differ.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ProofCacheKey {
+    commitment: Vec<u8>,
+    evaluation_point: Vec<u8>,
+    params_hash: [u8; 32],
+}
+
+impl ProofCacheKey {
+    fn new(commitment: &[u8], evaluation_point: &[B128], fri_params: &FRIParams<B128>) -> Self {
+        Self {
+            commitment: commitment.to_vec(),
+            evaluation_point: encode_point(evaluation_point),
+            params_hash: params_hash(fri_params),
+        }
+    }
+}
+
+/// Exact encoding of an evaluation point, 16 little-endian bytes per coordinate
+pub(crate) fn encode_point(evaluation_point: &[B128]) -> Vec<u8> {
+    evaluation_point
+        .iter()
+        .flat_map(|&coordinate| u128::from(coordinate).to_le_bytes())
+        .collect()
+}
+
+/// Bounded map with least-recently-used eviction, shared by the caches in this module
+///
+/// Both `get` hits and `insert` refresh an entry. When inserting a new key into a full
+/// map, the entry with the oldest access is evicted. Eviction scans all entries, which
+/// is fine for the small capacities this is meant for. A capacity of zero stores nothing.
+struct LruMap<K, V> {
+    capacity: usize,
+    entries: HashMap<K, (V, u64)>,
+    clock: u64,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> LruMap<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            clock: 0,
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(key).map(|(value, last_used)| {
+            *last_used = clock;
+            value.clone()
+        })
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.clock += 1;
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (value, self.clock));
+    }
+}
+
+/// Bounded LRU cache of evaluation proofs keyed by
+/// `(commitment, evaluation_point, params_hash)`
+///
+/// # Eviction policy
+///
+/// Least-recently-used: both `get` hits and `insert` refresh an entry. When inserting
+/// a new key into a full cache, the entry with the oldest access is evicted. Eviction
+/// scans all entries, which is fine for the small capacities this is meant for.
+/// A capacity of zero disables caching entirely.
+pub struct ProofCache {
+    entries: LruMap<ProofCacheKey, VerifierTranscript<StdChallenger>>,
+}
+
+impl ProofCache {
+    /// Create an empty cache holding at most `capacity` proofs
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruMap::new(capacity),
+        }
+    }
+
+    /// Look up a cached proof, refreshing its LRU position on hit
+    ///
+    /// Returns a fresh copy of the transcript, positioned at the start of the proof.
+    #[must_use]
+    pub fn get(
+        &mut self,
+        commitment: &[u8],
+        evaluation_point: &[B128],
+        fri_params: &FRIParams<B128>,
+    ) -> Option<VerifierTranscript<StdChallenger>> {
+        self.entries.get(&ProofCacheKey::new(
+            commitment,
+            evaluation_point,
+            fri_params,
+        ))
+    }
+
+    /// Store a proof, evicting the least recently used entry if the cache is full
+    pub fn insert(
+        &mut self,
+        commitment: &[u8],
+        evaluation_point: &[B128],
+        fri_params: &FRIParams<B128>,
+        transcript: VerifierTranscript<StdChallenger>,
+    ) {
+        self.entries.insert(
+            ProofCacheKey::new(commitment, evaluation_point, fri_params),
+            transcript,
+        );
+    }
+
+    /// Number of cached proofs
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.entries.len()
+    }
+
+    /// Whether the cache holds no proofs
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.entries.is_empty()
+    }
+
+    /// Drop all cached proofs
+    pub fn clear(&mut self) {
+        self.entries.entries.clear();
+    }
+}
+
+/// SHA-256 fingerprint of serialized proof bytes
+#[must_use]
+pub fn transcript_fingerprint(proof_bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(proof_bytes).into()
+}
+
+/// Digest of the FRI parameters a verification result depends on
+fn params_hash(fri_params: &FRIParams<B128>) -> [u8; 32] {
+    [
+        fri_params.rs_code().log_dim(),
+        fri_params.rs_code().log_inv_rate(),
+        fri_params.log_batch_size(),
+        fri_params.n_test_queries(),
+    ]
+    .iter()
+    .fold(Sha256::new(), |hasher, param| {
+        hasher.chain_update((*param as u64).to_le_bytes())
+    })
+    .finalize()
+    .into()
+}
+
+/// Cache key: proof fingerprint plus everything else the verifier is given
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct VerifiedProofKey {
+    fingerprint: [u8; 32],
+    evaluation_claim: u128,
+    evaluation_point: Vec<u8>,
+    params_hash: [u8; 32],
+}
+
+/// Bounded LRU cache of verification results keyed by
+/// `(transcript_fingerprint, claim, point, params_hash)`
+///
+/// Both accepts and rejects are cached, so a node serving the same proof to many
+/// clients runs `verify_evaluation` once. Uses the same eviction policy as
+/// [`ProofCache`].
+///
+/// # Risk
+///
+/// A hit returns the stored result without looking at the proof again. The key covers
+/// the parameters in [`FRIParams`] that select a verification path, but not the Merkle
+/// scheme or anything configured outside them, so scope each cache to a single
+/// parameter set and [`clear`](Self::clear) it when the verifier configuration changes.
+pub struct VerifiedProofCache {
+    entries: LruMap<VerifiedProofKey, Result<(), FriVeilError>>,
+}
+
+impl VerifiedProofCache {
+    /// Create an empty cache holding at most `capacity` results
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruMap::new(capacity),
+        }
+    }
+
+    /// Return the cached result for this verification, or run `verify` and cache it
+    ///
+    /// # Arguments
+    ///
+    /// * `proof_bytes` - Serialized proof being verified
+    /// * `evaluation_claim` - Claimed evaluation result
+    /// * `evaluation_point` - Point at which the polynomial was evaluated
+    /// * `fri_params` - FRI parameters used for verification
+    /// * `verify` - Runs the actual verification on a miss
+    pub fn get_or_verify(
+        &mut self,
+        proof_bytes: &[u8],
+        evaluation_claim: B128,
+        evaluation_point: &[B128],
+        fri_params: &FRIParams<B128>,
+        verify: impl FnOnce() -> Result<(), FriVeilError>,
+    ) -> Result<(), FriVeilError> {
+        let key = VerifiedProofKey {
+            fingerprint: transcript_fingerprint(proof_bytes),
+            evaluation_claim: u128::from(evaluation_claim),
+            evaluation_point: encode_point(evaluation_point),
+            params_hash: params_hash(fri_params),
+        };
+        if let Some(result) = self.entries.get(&key) {
+            return result;
+        }
+
+        let result = verify();
+        self.entries.insert(key, result.clone());
+        result
+    }
+
+    /// Number of cached results
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.entries.len()
+    }
+
+    /// Whether the cache holds no results
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.entries.is_empty()
+    }
+
+    /// Drop all cached results, e.g. after changing verifier parameters
+    pub fn clear(&mut self) {
+        self.entries.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{friveil::FriVeilDefault, test_utils::ProofFixture, traits::FriVeilUtils};
+
+    fn dummy_transcript(byte: u8) -> VerifierTranscript<StdChallenger> {
+        VerifierTranscript::new(StdChallenger::default(), vec![byte; 4])
+    }
+
+    #[test]
+    fn test_proof_cache_lru_eviction() {
+        let friveil = FriVeilDefault::new(1, 3, 12, 3);
+        let (fri_params, _) = friveil
+            .initialize_fri_context(5)
+            .expect("Failed to initialize FRI context");
+        let point = [B128::from(1u128), B128::from(2u128)];
+        let mut cache = ProofCache::new(2);
+
+        cache.insert(b"a", &point, &fri_params, dummy_transcript(1));
+        cache.insert(b"b", &point, &fri_params, dummy_transcript(2));
+        // Touch "a" so that "b" becomes the least recently used entry
+        assert!(cache.get(b"a", &point, &fri_params).is_some());
+        cache.insert(b"c", &point, &fri_params, dummy_transcript(3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(b"b", &point, &fri_params).is_none());
+        let hit = cache
+            .get(b"a", &point, &fri_params)
+            .expect("entry should still be cached");
+        assert_eq!(friveil.get_transcript_bytes(&hit), vec![1; 4]);
+
+        // Same commitment at a different point is a different key
+        assert!(cache.get(b"a", &[B128::from(1u128)], &fri_params).is_none());
+
+        // So is the same commitment and point under another query count
+        let (other_params, _) = FriVeilDefault::new(1, 4, 12, 3)
+            .initialize_fri_context(5)
+            .expect("Failed to initialize FRI context");
+        assert!(cache.get(b"a", &point, &other_params).is_none());
+    }
+
+    #[test]
+    fn test_proof_cache_zero_capacity() {
+        let (fri_params, _) = FriVeilDefault::new(1, 3, 12, 3)
+            .initialize_fri_context(5)
+            .expect("Failed to initialize FRI context");
+        let mut cache = ProofCache::new(0);
+        cache.insert(b"a", &[], &fri_params, dummy_transcript(1));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_prove_cached_returns_same_proof() {
+        let fixture = ProofFixture::new(512);
+        let mut cache = ProofCache::new(4);
+        let prove = |cache: &mut ProofCache| {
+            fixture
+                .friveil
+                .prove_cached(
+                    cache,
+                    fixture.packed_mle_values.packed_mle.clone(),
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                    &fixture.commit_output,
+                    &fixture.evaluation_point,
+                )
+                .expect("Failed to generate proof")
+        };
+
+        let first = prove(&mut cache);
+        assert_eq!(cache.len(), 1);
+        let second = prove(&mut cache);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            fixture.friveil.get_transcript_bytes(&first),
+            fixture.friveil.get_transcript_bytes(&second)
+        );
+        assert_eq!(
+            fixture.friveil.get_transcript_bytes(&first),
+            fixture.friveil.get_transcript_bytes(&fixture.prove())
+        );
+    }
+
+    #[test]
+    fn test_verified_proof_cache_hits_and_invalidation() {
+        let friveil = FriVeilDefault::new(1, 3, 12, 3);
+        let (fri_params, _) = friveil
+            .initialize_fri_context(5)
+            .expect("Failed to initialize FRI context");
+        let point = [B128::from(1u128), B128::from(2u128)];
+        let claim = B128::from(3u128);
+        let mut cache = VerifiedProofCache::new(4);
+        let mut runs = 0;
+
+        for _ in 0..3 {
+            let result = cache.get_or_verify(b"proof", claim, &point, &fri_params, || {
+                runs += 1;
+                Ok(())
+            });
+            assert!(result.is_ok());
+        }
+        assert_eq!(runs, 1);
+
+        // Rejections are cached too, under a different claim
+        let other_claim = B128::from(4u128);
+        for _ in 0..2 {
+            let result = cache.get_or_verify(b"proof", other_claim, &point, &fri_params, || {
+                runs += 1;
+                Err(FriVeilError::InvalidInput("bad proof".to_string()))
+            });
+            assert_eq!(
+                result,
+                Err(FriVeilError::InvalidInput("bad proof".to_string()))
+            );
+        }
+        assert_eq!(runs, 2);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        let _ = cache.get_or_verify(b"proof", claim, &point, &fri_params, || {
+            runs += 1;
+            Ok(())
+        });
+        assert_eq!(runs, 3);
+    }
+
+    #[test]
+    fn test_verify_evaluation_cached() {
+        let fixture = ProofFixture::new(512);
+        let proof_bytes = fixture.friveil.get_transcript_bytes(&fixture.prove());
+        let mut cache = VerifiedProofCache::new(4);
+        let verify = |cache: &mut VerifiedProofCache, evaluation_claim: B128| {
+            fixture.friveil.verify_evaluation_cached(
+                cache,
+                &proof_bytes,
+                evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
+
+        for _ in 0..2 {
+            let result = verify(&mut cache, fixture.evaluation_claim);
+            assert!(result.is_ok(), "Verification failed: {:?}", result);
+        }
+        assert_eq!(cache.len(), 1);
+
+        let wrong_claim = fixture.evaluation_claim + B128::from(1u128);
+        assert!(verify(&mut cache, wrong_claim).is_err());
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -0,0 +1,137 @@
+//! Error type returned by every fallible FRI-Veil API
+//!
+//! Most variants carry the human-readable message the failing check produced, so
+//! `Display` reads the same as the plain strings these APIs used to return while callers
+//! can match on the kind of failure. Header, binding, expiry and evaluation point checks
+//! carry structured fields instead, so callers can tell which value differed without
+//! parsing the message.
+
+use std::fmt;
+
+/// Errors from committing, proving, verifying and (de)serializing with FRI-Veil
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FriVeilError {
+    /// A size or log length doesn't fit the configured `n_vars`, or is below the minimum
+    InvalidLogLen(String),
+    /// A root, CID, nonced root or header digest differs from the expected commitment
+    CommitmentMismatch(String),
+    /// A share, record, cell or sample index lies outside the committed data
+    QueryOutOfRange(String),
+    /// A proof, bundle or cache entry ends before a value the reader needs
+    TranscriptTruncated(String),
+    /// Bytes or text don't decode to field elements or to the expected encoding
+    FieldDecode(String),
+    /// A dimension given by the caller doesn't match the one the parameters imply
+    ParameterMismatch(String),
+    /// A proof header or encoding header field differs from what the reader expects
+    HeaderMismatch {
+        /// Name of the differing field, e.g. `"log_inv_rate"`
+        field: &'static str,
+        /// Value the reader expects
+        expected: usize,
+        /// Value found in the header
+        got: usize,
+    },
+    /// A context entry of the proof's binding differs from the verifier's
+    BindingMismatch {
+        /// Name of the differing entry, e.g. `"epoch"`
+        field: &'static str,
+        /// Description of the expected entry
+        expected: String,
+        /// Description of the entry the proof carries
+        got: String,
+    },
+    /// The proof's binding expired before the verifier's current time
+    Expired {
+        /// Last timestamp at which the proof is valid
+        valid_until: u64,
+        /// Verifier's current time
+        now: u64,
+    },
+    /// The proof was generated for a different evaluation point
+    PointMismatch,
+    /// Caller input is malformed: empty, wrongly sized or otherwise unusable
+    InvalidInput(String),
+    /// No bytes were given to pack into an MLE
+    EmptyInput,
+    /// More bytes were given than an MLE can hold
+    DataTooLarge {
+        /// Byte length of the input
+        got: usize,
+        /// Largest accepted byte length
+        max: usize,
+    },
+    /// A binius commitment, proving, verification or buffer routine failed
+    Binius(String),
+    /// The commit output's codeword was dropped, so it can no longer be proven against
+    CodewordPruned,
+    /// Reading input from disk or seeding from the OS failed
+    Io(String),
+}
+
+impl FriVeilError {
+    /// Wrap an error reported by a binius routine
+    pub(crate) fn binius(error: impl fmt::Display) -> Self {
+        Self::Binius(error.to_string())
+    }
+}
+
+impl fmt::Display for FriVeilError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLogLen(message)
+            | Self::CommitmentMismatch(message)
+            | Self::QueryOutOfRange(message)
+            | Self::TranscriptTruncated(message)
+            | Self::FieldDecode(message)
+            | Self::ParameterMismatch(message)
+            | Self::InvalidInput(message)
+            | Self::Binius(message)
+            | Self::Io(message) => f.write_str(message),
+            Self::HeaderMismatch {
+                field,
+                expected,
+                got,
+            } => write!(
+                f,
+                "Header mismatch: expected {} {} but got {}",
+                field, expected, got
+            ),
+            Self::BindingMismatch {
+                field,
+                expected,
+                got,
+            } => write!(
+                f,
+                "Binding mismatch on {}: expected {} but proof is for {}",
+                field, expected, got
+            ),
+            Self::Expired { valid_until, now } => write!(
+                f,
+                "Proof expired: valid until {} but current time is {}",
+                valid_until, now
+            ),
+            Self::PointMismatch => {
+                f.write_str("Evaluation point mismatch: proof was generated for a different point")
+            }
+            Self::CodewordPruned => {
+                f.write_str("Codeword pruned: the commit output can no longer be proven against")
+            }
+            Self::EmptyInput => f.write_str("Empty input: no bytes to pack"),
+            Self::DataTooLarge { got, max } => write!(
+                f,
+                "Data too large: {} bytes exceeds max_bytes = {}",
+                got, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FriVeilError {}
+
+impl From<std::io::Error> for FriVeilError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
@@ -19,9 +19,19 @@
 //!                    Inclusion Proofs + Sampling
 //! ```
 
-use crate::traits::{FriVeilSampling, FriVeilUtils};
+use crate::{
+    audit::AuditRecord,
+    binding::{MAX_BINDING_LEN, ProofBinding},
+    bundle::ProofBundle,
+    cache::{ProofCache, VerifiedProofCache, encode_point},
+    error::FriVeilError,
+    header::{ProofHeader, field_id},
+    poly::{BYTES_PER_ELEMENT, PackedMLE, Utils, sparse_to_dense_values},
+    store::{CommitmentStore, StoredCommitOutput},
+    traits::{CommitOutputExt, FriVeilSampling, FriVeilUtils},
+};
 pub use binius_field::PackedField;
-use binius_field::{ExtensionField, Field, PackedExtension, Random};
+use binius_field::{ExtensionField, Field, PackedExtension};
 use binius_math::{
     BinarySubspace, FieldBuffer, FieldSliceMut, ReedSolomonCode,
     inner_product::inner_product,
@@ -47,13 +57,27 @@ use binius_verifier::{
     pcs::verify,
 };
 use itertools::Itertools;
-use rand::{SeedableRng, rngs::StdRng};
-use std::{iter::repeat_with, marker::PhantomData, mem::MaybeUninit};
+use rand::{TryRngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::Range,
+    sync::{Arc, PoisonError, RwLock},
+};
 use tracing::debug;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Smallest supported packed buffer log length (two B128 elements)
+pub const MIN_PACKED_LOG_LEN: usize = 1;
+
+/// Smallest supported number of MLE variables: [`MIN_PACKED_LOG_LEN`] plus 7 bit variables
+pub const MIN_TOTAL_N_VARS: usize = MIN_PACKED_LOG_LEN + 7;
+
 /// Default FRI-Veil configuration using 128-bit binary fields
 ///
 /// This type alias provides a convenient default configuration with:
@@ -67,6 +91,10 @@ pub type FriVeilDefault = FriVeil<
     NeighborsLastMultiThread<GenericPreExpanded<B128>>,
 >;
 
+/// NTTs built by [`FriVeil::fri_context_cached`], keyed by packed buffer log length
+type NttCache =
+    Arc<RwLock<HashMap<usize, Arc<NeighborsLastMultiThread<GenericPreExpanded<B128>>>>>>;
+
 /// FRI-Veil polynomial commitment scheme
 ///
 /// Generic over:
@@ -87,15 +115,44 @@ where
     VCS: MerkleTreeScheme<P::Scalar>,
 {
     _ntt: PhantomData<&'a NTT>,
-    pub merkle_prover:
+    pub merkle_prover: Arc<
         BinaryMerkleTreeProver<P::Scalar, StdDigest, ParallelCompressionAdaptor<StdCompression>>,
+    >,
     log_inv_rate: usize,
     num_test_queries: usize,
     n_vars: usize,
     log_num_shares: usize,
+    ntt_cache: NttCache,
     _vcs: PhantomData<VCS>,
 }
 
+/// Cheap clone sharing the Merkle prover behind an [`Arc`]
+///
+/// Every method takes `&self` and the prover holds no per-commitment state, so clones
+/// can prove concurrently without affecting each other. Reed-Solomon codes and NTT
+/// domains are not part of the instance; they are built by
+/// [`FriVeil::initialize_fri_context`] and passed to each call. NTTs memoized by
+/// [`FriVeil::fri_context_cached`] are shared between clones.
+impl<P, VCS, NTT> Clone for FriVeil<'_, P, VCS, NTT>
+where
+    NTT: AdditiveNTT<Field = B128> + Sync,
+    P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
+    VCS: MerkleTreeScheme<P::Scalar>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _ntt: PhantomData,
+            merkle_prover: Arc::clone(&self.merkle_prover),
+            log_inv_rate: self.log_inv_rate,
+            num_test_queries: self.num_test_queries,
+            n_vars: self.n_vars,
+            log_num_shares: self.log_num_shares,
+            ntt_cache: Arc::clone(&self.ntt_cache),
+            _vcs: PhantomData,
+        }
+    }
+}
+
 impl<'a, P, VCS, NTT> FriVeil<'a, P, VCS, NTT>
 where
     P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
@@ -113,6 +170,7 @@ where
     ///   - Typical values: 64-128 for good security
     /// * `n_vars` - Number of variables in the multilinear polynomial
     /// * `log_num_shares` - Logarithm of Merkle tree shares
+    #[must_use]
     pub fn new(
         log_inv_rate: usize,
         num_test_queries: usize,
@@ -120,13 +178,14 @@ where
         log_num_shares: usize,
     ) -> Self {
         Self {
-            merkle_prover: BinaryMerkleTreeProver::<P::Scalar, StdDigest, _>::new(
+            merkle_prover: Arc::new(BinaryMerkleTreeProver::<P::Scalar, StdDigest, _>::new(
                 ParallelCompressionAdaptor::new(StdCompression::default()),
-            ),
+            )),
             log_inv_rate,
             num_test_queries,
             n_vars,
             log_num_shares,
+            ntt_cache: Arc::default(),
             _ntt: PhantomData,
             _vcs: PhantomData,
         }
@@ -146,7 +205,18 @@ where
     /// # Returns
     ///
     /// * `Ok((FRIParams, NTT))` - FRI parameters and NTT instance
-    /// * `Err(String)` - Error message if initialization fails
+    /// * `Err(FriVeilError)` - Error message if initialization fails, including sizes below
+    ///   [`MIN_PACKED_LOG_LEN`] / [`MIN_TOTAL_N_VARS`]
+    ///
+    /// # Minimum size
+    ///
+    /// A multilinear over bits always spans whole B128 elements, so the smallest MLE this
+    /// crate can represent already has 7 variables; a constant (`total_n_vars == 0`) has
+    /// no representation at all. The smallest supported MLE is two elements, i.e.
+    /// `packed_buffer_log_len == 1` and `total_n_vars == 8`: a single element gives a
+    /// Reed-Solomon message of dimension 1 with no FRI folding rounds. Smaller sizes,
+    /// such as an input of 16 bytes or fewer, are rejected as invalid parameters rather
+    /// than handled by a degenerate path.
     pub fn initialize_fri_context(
         &self,
         packed_buffer_log_len: usize,
@@ -155,55 +225,294 @@ where
             FRIParams<P::Scalar>,
             NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
         ),
-        String,
+        FriVeilError,
+    > {
+        let fri_params = self.fri_params_for(packed_buffer_log_len)?;
+        let ntt = self.ntt_for(&fri_params)?;
+        Ok((fri_params, ntt))
+    }
+
+    /// Like [`Self::initialize_fri_context`], but reuse the NTT across calls
+    ///
+    /// Building the NTT domain is the expensive part of setup, and for a given instance
+    /// it only depends on `packed_buffer_log_len`. The first call for a length builds it
+    /// and keeps it in the instance; later calls, from this instance or any clone of it,
+    /// return the same [`Arc`]. The FRI parameters are cheap and are rebuilt every call.
+    /// A panic elsewhere while the cache lock is held doesn't make later calls panic: the
+    /// map is only ever updated by a single insertion, so it is used as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `packed_buffer_log_len` - Log length of the packed buffers to be committed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((FRIParams, Arc<NTT>))` - FRI parameters and the shared NTT instance
+    /// * `Err(FriVeilError)` - Same errors as [`Self::initialize_fri_context`]
+    #[allow(clippy::type_complexity)]
+    pub fn fri_context_cached(
+        &self,
+        packed_buffer_log_len: usize,
+    ) -> Result<
+        (
+            FRIParams<P::Scalar>,
+            Arc<NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>>,
+        ),
+        FriVeilError,
     > {
+        let fri_params = self.fri_params_for(packed_buffer_log_len)?;
+        let cached = self
+            .ntt_cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&packed_buffer_log_len)
+            .cloned();
+        if let Some(ntt) = cached {
+            return Ok((fri_params, ntt));
+        }
+
+        // Built outside the lock; if another thread raced us, keep the first insertion
+        let ntt = Arc::new(self.ntt_for(&fri_params)?);
+        let ntt = Arc::clone(
+            self.ntt_cache
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(packed_buffer_log_len)
+                .or_insert(ntt),
+        );
+        Ok((fri_params, ntt))
+    }
+
+    /// FRI parameters for packed buffers of log length `packed_buffer_log_len`
+    ///
+    /// The cheap half of [`Self::initialize_fri_context`], for verifiers that never
+    /// encode and so have no use for the NTT.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FRIParams)` - FRI parameters for the configured rate and query count
+    /// * `Err(FriVeilError)` - Same errors as [`Self::initialize_fri_context`]
+    pub fn fri_params_for(
+        &self,
+        packed_buffer_log_len: usize,
+    ) -> Result<FRIParams<P::Scalar>, FriVeilError> {
+        if packed_buffer_log_len < MIN_PACKED_LOG_LEN || self.n_vars < MIN_TOTAL_N_VARS {
+            return Err(FriVeilError::InvalidLogLen(format!(
+                "Invalid params: packed log length {} and n_vars {} are below the minimum \
+                 supported {} and {}",
+                packed_buffer_log_len, self.n_vars, MIN_PACKED_LOG_LEN, MIN_TOTAL_N_VARS
+            )));
+        }
+
         let committed_rs_code =
-            ReedSolomonCode::<B128>::new(packed_buffer_log_len, self.log_inv_rate).unwrap();
+            ReedSolomonCode::<B128>::new(packed_buffer_log_len, self.log_inv_rate)
+                .map_err(FriVeilError::binius)?;
 
         let fri_log_batch_size = 0;
 
-        let fri_arities = if P::LOG_WIDTH == 2 {
+        FRIParams::new(
+            committed_rs_code,
+            fri_log_batch_size,
+            Self::fri_arities(packed_buffer_log_len),
+            self.num_test_queries,
+        )
+        .map_err(FriVeilError::binius)
+    }
+
+    /// Fold arities of the FRI rounds for packed buffers of log length `packed_buffer_log_len`
+    fn fri_arities(packed_buffer_log_len: usize) -> Vec<usize> {
+        if P::LOG_WIDTH == 2 {
             vec![2, 2]
         } else {
             vec![2; packed_buffer_log_len / 2]
-        };
+        }
+    }
 
-        let fri_params = FRIParams::new(
-            committed_rs_code.clone(),
-            fri_log_batch_size,
-            fri_arities,
-            self.num_test_queries,
-        )
-        .map_err(|e| e.to_string())?;
+    /// Upper bound on the size of an honest serialized proof under `fri_params`
+    ///
+    /// Verifiers that take proofs from untrusted sources, such as a zkVM guest, use this
+    /// to reject oversized inputs before buffering them; see
+    /// `FriVeilUtils::reconstruct_transcript_bounded`.
+    ///
+    /// # Bound
+    ///
+    /// Each part of the proof is bounded separately, for the fold arities
+    /// [`Self::initialize_fri_context`] configures:
+    /// - prefix: header, binding of at most [`MAX_BINDING_LEN`] bytes, point digest, root
+    /// - ring switching: one element per bit of a B128 element
+    /// - sumcheck: three coefficients per variable, plus a few final evaluations
+    /// - FRI: one root per oracle and the terminal codeword
+    /// - queries: per query and oracle, a coset of the largest arity and a full-depth
+    ///   Merkle path
+    ///
+    /// Everything after the prefix is the binius PCS proof, whose encoding is not pinned
+    /// by this crate, so that part is doubled as slack. The bound is therefore loose, but
+    /// it follows the parameters rather than whatever length an input claims.
+    #[must_use]
+    pub fn max_proof_bytes(&self, fri_params: &FRIParams<P::Scalar>) -> usize {
+        Self::max_proof_len(fri_params)
+    }
+
+    /// Lower bound on the size of any proof under `fri_params` that can verify
+    ///
+    /// Relays use this together with [`Self::max_proof_bytes`] to drop proofs that cannot
+    /// hold the data `fri_params` demands; see [`verify_proof_structure`].
+    ///
+    /// # Bound
+    ///
+    /// Counts only what every proof must contain, whatever the binius encoding of the
+    /// rest: the prefix with an empty binding, and for each test query the opened coset
+    /// of the committed codeword, one value per position of the first fold. Merkle paths,
+    /// sumcheck messages and later oracles are not counted, so honest proofs are
+    /// considerably longer.
+    #[must_use]
+    pub fn min_proof_bytes(&self, fri_params: &FRIParams<P::Scalar>) -> usize {
+        Self::min_proof_len(fri_params)
+    }
+
+    /// [`Self::min_proof_bytes`], without an instance
+    fn min_proof_len(fri_params: &FRIParams<P::Scalar>) -> usize {
+        const DIGEST_BYTES: usize = 32;
+        let first_arity = Self::fri_arities(fri_params.rs_code().log_dim())
+            .first()
+            .copied()
+            .unwrap_or(0);
+        let coset_bytes = (1 << (first_arity + fri_params.log_batch_size())) * BYTES_PER_ELEMENT;
+
+        let prefix = ProofHeader::ENCODED_LEN + 2 + 2 * DIGEST_BYTES;
+        prefix + fri_params.n_test_queries() * coset_bytes
+    }
 
-        let subspace = BinarySubspace::with_dim(fri_params.rs_code().log_len()).unwrap();
+    /// [`Self::max_proof_bytes`], without an instance
+    fn max_proof_len(fri_params: &FRIParams<P::Scalar>) -> usize {
+        const DIGEST_BYTES: usize = 32;
+        let log_batch_size = fri_params.log_batch_size();
+        let log_dim = fri_params.rs_code().log_dim();
+        let log_len = fri_params.rs_code().log_len();
+        let arities = Self::fri_arities(log_dim);
+        let folded_vars: usize = arities.iter().sum();
+
+        let prefix = ProofHeader::ENCODED_LEN + 2 + MAX_BINDING_LEN + 2 * DIGEST_BYTES;
+        let ring_switching =
+            (1 << <P::Scalar as ExtensionField<B1>>::LOG_DEGREE) * BYTES_PER_ELEMENT;
+        let sumcheck = (3 * (log_dim + log_batch_size) + 4) * BYTES_PER_ELEMENT;
+        let oracle_roots = (arities.len() + 1) * DIGEST_BYTES;
+        let terminal_codeword = (1 << log_len.saturating_sub(folded_vars)) * BYTES_PER_ELEMENT;
+        let max_coset_log_len = arities.iter().copied().max().unwrap_or(0) + log_batch_size;
+        let per_query = (arities.len() + 1)
+            * ((1 << max_coset_log_len) * BYTES_PER_ELEMENT + log_len * DIGEST_BYTES);
+        let queries = fri_params.n_test_queries() * per_query;
+
+        prefix + 2 * (ring_switching + sumcheck + oracle_roots + terminal_codeword + queries)
+    }
+
+    /// NTT over the evaluation domain of `fri_params`' Reed-Solomon code
+    fn ntt_for(
+        &self,
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>, FriVeilError> {
+        let subspace = BinarySubspace::with_dim(fri_params.rs_code().log_len())
+            .map_err(FriVeilError::binius)?;
 
         let domain_context = domain_context::GenericPreExpanded::generate_from_subspace(&subspace);
-        let ntt = NeighborsLastMultiThread::new(domain_context, self.log_num_shares);
+        Ok(NeighborsLastMultiThread::new(
+            domain_context,
+            self.log_num_shares,
+        ))
+    }
 
-        Ok((fri_params, ntt))
+    /// Check that a packed buffer log length matches this instance's `n_vars`
+    ///
+    /// A verifier that rebuilds its context from a transmitted packed log length
+    /// reconstructs `n_vars` as `packed_buffer_log_len + 7` (the B128 bit width). If the
+    /// field width changed or the length was miscomputed, calling this before
+    /// `initialize_fri_context` names both values instead of failing opaquely later.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The implied number of variables equals `n_vars`
+    /// * `Err(FriVeilError)` - Parameter mismatch naming the expected and implied values
+    pub fn check_packed_log_len(&self, packed_buffer_log_len: usize) -> Result<(), FriVeilError> {
+        let implied_n_vars = packed_buffer_log_len + <P::Scalar as ExtensionField<B1>>::LOG_DEGREE;
+        if implied_n_vars != self.n_vars {
+            return Err(FriVeilError::ParameterMismatch(format!(
+                "Parameter mismatch: expected n_vars {} but packed log length {} implies n_vars {}",
+                self.n_vars, packed_buffer_log_len, implied_n_vars
+            )));
+        }
+        Ok(())
     }
 
     /// Generate a random evaluation point for polynomial evaluation
     ///
     /// Creates a random point in the n-dimensional space for evaluating
-    /// the multilinear polynomial. Uses a fixed seed for reproducibility.
+    /// the multilinear polynomial, seeded from the operating system's RNG. Every call
+    /// returns a fresh point; use [`Self::calculate_evaluation_point_from_seed`] where
+    /// prover and verifier, or repeated runs, must agree on the point.
     ///
     /// # Returns
     ///
     /// * `Ok(Vec<P::Scalar>)` - Random evaluation point with `n_vars` coordinates
-    /// * `Err(String)` - Error message (currently never fails)
+    /// * `Err(FriVeilError)` - The OS RNG failed to produce a seed
+    pub fn calculate_evaluation_point_random(&self) -> Result<Vec<P::Scalar>, FriVeilError> {
+        let mut seed = [0u8; 32];
+        OsRng.try_fill_bytes(&mut seed).map_err(|e| {
+            FriVeilError::Io(format!(
+                "Failed to seed evaluation point from the OS: {}",
+                e
+            ))
+        })?;
+        self.calculate_evaluation_point_from_seed(seed)
+    }
+
+    /// Generate the evaluation point determined by `seed`
     ///
-    /// # Note
+    /// Expands `seed` with [`expand_point`], so the same seed and `n_vars` give the same
+    /// point on every run, platform and dependency version, which keeps tests and fixed
+    /// protocols reproducible. The point is only as unpredictable as the seed.
     ///
-    /// Uses a fixed seed `[0; 32]` for deterministic behavior in tests.
-    /// For production use, consider using a cryptographically secure RNG.
-    pub fn calculate_evaluation_point_random(&self) -> Result<Vec<P::Scalar>, String> {
-        let mut rng = StdRng::from_seed([0; 32]);
-        let evaluation_point: Vec<P::Scalar> = repeat_with(|| P::Scalar::random(&mut rng))
-            .take(self.n_vars)
-            .collect();
-        Ok(evaluation_point)
+    /// # Arguments
+    ///
+    /// * `seed` - Seed the point is expanded from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<P::Scalar>)` - Evaluation point with `n_vars` coordinates
+    /// * `Err(FriVeilError)` - Error message (currently never fails)
+    pub fn calculate_evaluation_point_from_seed(
+        &self,
+        seed: [u8; 32],
+    ) -> Result<Vec<P::Scalar>, FriVeilError> {
+        Ok(expand_point(&seed, self.n_vars))
+    }
+
+    /// Derive the evaluation point from the commitment via Fiat-Shamir
+    ///
+    /// The point is a hash of the commitment root, so it is fixed by the committed data
+    /// and cannot be chosen after committing to suit a particular polynomial. Prove at it
+    /// with [`Self::prove_at_commitment_point`] and verify with
+    /// [`Self::verify_evaluation_at_commitment_point`], which rederives it from the root.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<P::Scalar>` - Point with `n_vars` coordinates
+    pub fn calculate_evaluation_point_from_commitment<C>(
+        &self,
+        commit_output: &CommitOutput<P, Vec<u8>, C>,
+    ) -> Vec<P::Scalar> {
+        self.evaluation_point_from_root(&commit_output.commitment)
+    }
+
+    /// Point derived by [`Self::calculate_evaluation_point_from_commitment`] for `root`
+    #[must_use]
+    pub fn evaluation_point_from_root(&self, root: &[u8]) -> Vec<P::Scalar> {
+        let seed: [u8; 32] = Sha256::new()
+            .chain_update(b"friveil-commitment-point")
+            .chain_update(root)
+            .chain_update((self.n_vars as u64).to_le_bytes())
+            .finalize()
+            .into();
+        expand_point(&seed, self.n_vars)
     }
 
     /// Calculate the evaluation claim for a polynomial at a given point
@@ -219,7 +528,7 @@ where
     /// # Returns
     ///
     /// * `Ok(P::Scalar)` - The evaluation result (claim)
-    /// * `Err(String)` - Error if dimensions don't match
+    /// * `Err(FriVeilError)` - Error if dimensions don't match
     ///
     /// # Algorithm
     ///
@@ -232,7 +541,17 @@ where
         &self,
         values: &[P::Scalar],
         evaluation_point: &[P::Scalar],
-    ) -> Result<P::Scalar, String> {
+    ) -> Result<P::Scalar, FriVeilError> {
+        let num_bits = values.len() << <P::Scalar as ExtensionField<B1>>::LOG_DEGREE;
+        if evaluation_point.len() >= usize::BITS as usize || num_bits != 1 << evaluation_point.len()
+        {
+            return Err(FriVeilError::ParameterMismatch(format!(
+                "Dimension mismatch: {} evaluations cannot be evaluated at a point with {} coordinates",
+                num_bits,
+                evaluation_point.len()
+            )));
+        }
+
         // Convert to small field representation for efficient computation
         let lifted_small_field_mle = self.lift_small_to_large_field::<B1, P::Scalar>(
             &self.large_field_mle_to_small_field_mle::<B1, P::Scalar>(values),
@@ -251,6 +570,113 @@ where
         Ok(evaluation_claim)
     }
 
+    /// Calculate the evaluation claim for a point given in Lagrange form
+    ///
+    /// Lagrange form of a point `r` is the vector of basis weights `eq(i, r)` over the
+    /// boolean hypercube, i.e. exactly what `eq_ind_partial_eval(r)` returns. The claim
+    /// is then a plain inner product and matches [`Self::calculate_evaluation_claim`] at
+    /// the corresponding coordinate-form point.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Polynomial evaluations (coefficients in evaluation form)
+    /// * `lagrange_weights` - `2^n_vars` basis weights of the evaluation point
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(P::Scalar)` - The evaluation result (claim)
+    /// * `Err(FriVeilError)` - Error if the number of weights doesn't match `n_vars`
+    pub fn calculate_evaluation_claim_lagrange(
+        &self,
+        values: &[P::Scalar],
+        lagrange_weights: &[P::Scalar],
+    ) -> Result<P::Scalar, FriVeilError> {
+        if lagrange_weights.len() != 1 << self.n_vars {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Expected {} Lagrange weights for n_vars = {}, got {}",
+                1usize << self.n_vars,
+                self.n_vars,
+                lagrange_weights.len()
+            )));
+        }
+
+        let lifted_small_field_mle = self.lift_small_to_large_field::<B1, P::Scalar>(
+            &self.large_field_mle_to_small_field_mle::<B1, P::Scalar>(values),
+        );
+
+        Ok(inner_product::<P::Scalar>(
+            lifted_small_field_mle,
+            lagrange_weights.to_vec(),
+        ))
+    }
+
+    /// Recover the coordinate-form point whose Lagrange basis weights are `lagrange_weights`
+    ///
+    /// # Mapping
+    ///
+    /// For a point `r`, `w[k] = Π_j (r_j if bit j of k is set else 1 + r_j)`. Pick any
+    /// index `m` with `w[m] != 0` and let `k = m` with bit `i` cleared; then
+    /// `w[k] + w[k | 1 << i]` is the product of the other factors, so
+    /// ```text
+    /// r_i = w[k | 1 << i] / (w[k] + w[k | 1 << i])
+    /// ```
+    /// The recovered point is re-expanded and compared against the input, so weights that
+    /// are not the tensor-product basis of any point are rejected.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<P::Scalar>)` - Evaluation point with `n_vars` coordinates
+    /// * `Err(FriVeilError)` - Error if the weights have the wrong length or are not a valid basis
+    pub fn lagrange_weights_to_point(
+        &self,
+        lagrange_weights: &[P::Scalar],
+    ) -> Result<Vec<P::Scalar>, FriVeilError> {
+        if lagrange_weights.len() != 1 << self.n_vars {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Expected {} Lagrange weights for n_vars = {}, got {}",
+                1usize << self.n_vars,
+                self.n_vars,
+                lagrange_weights.len()
+            )));
+        }
+
+        let nonzero_index = lagrange_weights
+            .iter()
+            .position(|w| *w != P::Scalar::ZERO)
+            .ok_or_else(|| {
+                FriVeilError::InvalidInput("Lagrange weights are all zero".to_string())
+            })?;
+
+        let evaluation_point = (0..self.n_vars)
+            .map(|i| {
+                let lo = nonzero_index & !(1 << i);
+                let hi = lo | (1 << i);
+                let denominator = lagrange_weights[lo] + lagrange_weights[hi];
+                denominator
+                    .invert()
+                    .map(|inv| lagrange_weights[hi] * inv)
+                    .ok_or_else(|| {
+                        FriVeilError::InvalidInput(
+                            "Lagrange weights are not a valid basis".to_string(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expanded: Vec<P::Scalar> = eq_ind_partial_eval(&evaluation_point)
+            .as_ref()
+            .iter()
+            .copied()
+            .collect_vec();
+        if expanded != lagrange_weights {
+            return Err(FriVeilError::InvalidInput(
+                "Lagrange weights are not the basis of any evaluation point".to_string(),
+            ));
+        }
+
+        Ok(evaluation_point)
+    }
+
     /// Generate a polynomial commitment and codeword
     ///
     /// Creates a Merkle tree commitment to the Reed-Solomon encoded codeword.
@@ -268,7 +694,7 @@ where
     ///   - `codeword`: Reed-Solomon encoded values
     ///   - `commitment`: Merkle root (32 bytes)
     ///   - `committed`: Merkle tree structure for proof generation
-    /// * `Err(String)` - Error message if commitment fails
+    /// * `Err(FriVeilError)` - Error message if commitment fails
     ///
     /// # Example
     ///
@@ -281,20 +707,13 @@ where
         packed_mle: FieldBuffer<P>,
         fri_params: FRIParams<P::Scalar>,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
-    ) -> Result<
-        CommitOutput<
-            P,
-            Vec<u8>,
-            <BinaryMerkleTreeProver<
-                P::Scalar,
-                StdDigest,
-                ParallelCompressionAdaptor<StdCompression>,
-            > as MerkleTreeProver<P::Scalar>>::Committed,
-        >,
-        String,
-    > {
-        let pcs = OneBitPCSProver::new(ntt, &self.merkle_prover, &fri_params);
-        let commit_output = pcs.commit(packed_mle.clone()).map_err(|e| e.to_string())?;
+    ) -> Result<StoredCommitOutput<P>, FriVeilError> {
+        check_packed_mle_fits(packed_mle.log_len(), &fri_params)?;
+
+        let pcs = OneBitPCSProver::new(ntt, &*self.merkle_prover, &fri_params);
+        let commit_output = pcs
+            .commit(packed_mle.clone())
+            .map_err(FriVeilError::binius)?;
 
         // Convert the digest type to Vec<u8> for easier handling
         Ok(CommitOutput {
@@ -304,6 +723,41 @@ where
         })
     }
 
+    /// Commit to a mostly-zero polynomial given only its nonzero field elements
+    ///
+    /// The commitment is identical to [`Self::commit`] on the equivalent dense MLE.
+    ///
+    /// # Note
+    ///
+    /// Only building the MLE exploits sparsity. Reed-Solomon encoding runs a full additive
+    /// NTT inside the binius PCS prover, and every codeword symbol depends on every message
+    /// element, so encoding and Merkle hashing stay dense. Savings are limited to skipping
+    /// byte conversion of the zero region.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonzero` - Pairs of `(element_index, value)`; all other elements are zero
+    /// * `total_n_vars` - Total number of variables of the MLE
+    /// * `fri_params` - FRI protocol parameters
+    /// * `ntt` - NTT instance for encoding
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CommitOutput)` - Same output as [`Self::commit`]
+    /// * `Err(FriVeilError)` - Index out of range, or commitment failed
+    pub fn commit_sparse(
+        &self,
+        nonzero: &[(usize, P::Scalar)],
+        total_n_vars: usize,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+    ) -> Result<StoredCommitOutput<P>, FriVeilError> {
+        let values = sparse_to_dense_values(nonzero, total_n_vars)?;
+        let packed_mle =
+            FieldBuffer::<P>::from_values(values.as_slice()).map_err(FriVeilError::binius)?;
+        self.commit(packed_mle, fri_params, ntt)
+    }
+
     /// Generate an evaluation proof for the committed polynomial
     ///
     /// Creates a FRI-based proof that the polynomial evaluates to a specific
@@ -321,11 +775,12 @@ where
     /// # Returns
     ///
     /// * `Ok(VerifierTranscript)` - Transcript containing the proof
-    /// * `Err(String)` - Error message if proof generation fails
+    /// * `Err(FriVeilError)` - Error message if proof generation fails
     ///
     /// # Process
     ///
-    /// 1. Initialize prover transcript with commitment
+    /// 1. Initialize prover transcript with the [`ProofHeader`], the [`point_digest`] and
+    ///    the commitment
     /// 2. Run FRI protocol to generate proof
     /// 3. Convert to verifier transcript for verification
     ///
@@ -345,22 +800,61 @@ where
         packed_mle: FieldBuffer<P>,
         fri_params: FRIParams<P::Scalar>,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
-        commit_output: &CommitOutput<
-            P,
-            Vec<u8>,
-            <BinaryMerkleTreeProver<
-                P::Scalar,
-                StdDigest,
-                ParallelCompressionAdaptor<StdCompression>,
-            > as MerkleTreeProver<P::Scalar>>::Committed,
-        >,
+        commit_output: &StoredCommitOutput<P>,
+        evaluation_point: &[P::Scalar],
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        self.prove_bound(
+            packed_mle,
+            fri_params,
+            ntt,
+            commit_output,
+            evaluation_point,
+            &ProofBinding::new(),
+        )
+    }
+
+    /// Generate an evaluation proof bound to a context such as a chain epoch
+    ///
+    /// Same as [`Self::prove`], but writes `binding` into the transcript right after the
+    /// [`ProofHeader`], so every FRI challenge depends on it. The proof then only verifies
+    /// through [`Self::verify_evaluation_bound`] with the same binding, which stops a
+    /// proof for epoch N from being replayed at epoch M.
+    ///
+    /// The commitment root itself does not depend on the binding: committing the same
+    /// data in two epochs yields the same root, and only the proofs differ.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Transcript containing the bound proof
+    /// * `Err(FriVeilError)` - Error message if proof generation fails
+    pub fn prove_bound(
+        &self,
+        packed_mle: FieldBuffer<P>,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+        commit_output: &StoredCommitOutput<P>,
         evaluation_point: &[P::Scalar],
-    ) -> Result<VerifierTranscript<StdChallenger>, String> {
-        let pcs = OneBitPCSProver::new(ntt, &self.merkle_prover, &fri_params);
+        binding: &ProofBinding,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        check_not_pruned(commit_output)?;
+        check_packed_mle_fits(packed_mle.log_len(), &fri_params)?;
+        check_evaluation_point_len(evaluation_point.len(), &fri_params)?;
+
+        let pcs = OneBitPCSProver::new(ntt, &*self.merkle_prover, &fri_params);
 
         let mut prover_transcript = ProverTranscript::new(StdChallenger::default());
 
-        // Write commitment to transcript
+        // Write proof header, binding and point digest, then commitment to transcript
+        let header = ProofHeader::for_params(&fri_params)?;
+        prover_transcript.message().write_bytes(&header.to_bytes());
+        let binding_bytes = binding.to_bytes();
+        prover_transcript
+            .message()
+            .write_bytes(&(binding_bytes.len() as u16).to_le_bytes());
+        prover_transcript.message().write_bytes(&binding_bytes);
+        prover_transcript
+            .message()
+            .write_bytes(&point_digest(evaluation_point));
         prover_transcript
             .message()
             .write_bytes(&commit_output.commitment);
@@ -373,1060 +867,5824 @@ where
             evaluation_point.to_vec(),
             &mut prover_transcript,
         )
-        .map_err(|e| e.to_string())?;
+        .map_err(FriVeilError::binius)?;
 
         Ok(prover_transcript.into_verifier())
     }
 
-    /// Encode data using Reed-Solomon code with NTT
-    ///
-    /// This is a helper function to observe NTT encoding behavior outside
-    /// the `commit` function. Applies Reed-Solomon encoding to expand data
-    /// with redundancy for error correction.
-    ///
-    /// # Arguments
+    /// Generate an evaluation proof at the point derived from the commitment
     ///
-    /// * `data` - Input data to encode
-    /// * `fri_params` - FRI parameters containing Reed-Solomon code configuration
-    /// * `ntt` - NTT instance for efficient encoding
+    /// Same as [`Self::prove`] at [`Self::calculate_evaluation_point_from_commitment`].
     ///
     /// # Returns
     ///
-    /// * `Ok(Vec<P::Scalar>)` - Encoded codeword with redundancy
-    /// * `Err(String)` - Error message if encoding fails
-    ///
-    /// # Note
-    ///
-    /// This function is marked `#[allow(dead_code)]` as it's primarily used
-    /// for testing and debugging. The `commit` function handles encoding internally.
-    #[allow(dead_code)]
-    pub fn encode_codeword(
+    /// * `Ok(VerifierTranscript)` - Transcript containing the proof
+    /// * `Err(FriVeilError)` - Error message if proof generation fails
+    pub fn prove_at_commitment_point(
         &self,
-        data: &[P::Scalar],
+        packed_mle: FieldBuffer<P>,
         fri_params: FRIParams<P::Scalar>,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
-    ) -> Result<Vec<P::Scalar>, String> {
-        let rs_code = fri_params.rs_code();
-        let len = 1
-            << (rs_code.log_dim() + fri_params.log_batch_size() - P::LOG_WIDTH
-                + rs_code.log_inv_rate());
-
-        let mut encoded = Vec::with_capacity(len);
-
-        rs_code
-            .encode_batch(
-                ntt,
-                data.as_ref(),
-                encoded.spare_capacity_mut(),
-                fri_params.log_batch_size(),
-            )
-            .map_err(|e| e.to_string())?;
-
-        unsafe {
-            // Safety: encode_ext_batch guarantees all elements are initialized on success
-            encoded.set_len(len);
-        }
-
-        Ok(encoded)
+        commit_output: &StoredCommitOutput<P>,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        let evaluation_point = self.calculate_evaluation_point_from_commitment(commit_output);
+        self.prove(
+            packed_mle,
+            fri_params,
+            ntt,
+            commit_output,
+            &evaluation_point,
+        )
     }
 
-    /// Lift elements from a small field to a large extension field
-    ///
-    /// Converts field elements from a base field `F` to an extension field `FE`.
-    /// This is used for efficient computation in the extension field.
+    /// Generate an evaluation proof and return its serialized bytes
     ///
-    /// # Type Parameters
+    /// Equivalent to [`Self::prove`] followed by `get_transcript_bytes`, for callers that
+    /// only ship the proof and never need the transcript object. The bytes can be turned
+    /// back into a transcript with `reconstruct_transcript_from_bytes`.
     ///
-    /// * `F` - Base field type
-    /// * `FE` - Extension field type (must extend `F`)
+    /// # Returns
     ///
-    /// # Arguments
+    /// * `Ok(Vec<u8>)` - Serialized proof
+    /// * `Err(FriVeilError)` - Error message if proof generation fails
+    pub fn prove_to_bytes(
+        &self,
+        packed_mle: FieldBuffer<P>,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+        commit_output: &StoredCommitOutput<P>,
+        evaluation_point: &[P::Scalar],
+    ) -> Result<Vec<u8>, FriVeilError> {
+        let transcript =
+            self.prove(packed_mle, fri_params, ntt, commit_output, evaluation_point)?;
+        Ok(transcript_bytes(&transcript))
+    }
+
+    /// Generate an evaluation proof, reusing a previously cached one when available
     ///
-    /// * `small_field_elms` - Elements in the base field
+    /// Consults `cache` with `(commit_output.commitment, evaluation_point, fri_params)`
+    /// before running the prover; on a miss the freshly generated proof is inserted.
+    /// Arguments are otherwise identical to [`Self::prove`].
     ///
     /// # Returns
     ///
-    /// Vector of elements lifted to the extension field
-    pub fn lift_small_to_large_field<F, FE>(&self, small_field_elms: &[F]) -> Vec<FE>
-    where
-        F: Field,
-        FE: Field + ExtensionField<F>,
-    {
-        small_field_elms.iter().map(|&elm| FE::from(elm)).collect()
+    /// * `Ok(VerifierTranscript)` - Cached or newly generated proof transcript
+    /// * `Err(FriVeilError)` - Error message if proof generation fails
+    pub fn prove_cached(
+        &self,
+        cache: &mut ProofCache,
+        packed_mle: FieldBuffer<P>,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+        commit_output: &StoredCommitOutput<P>,
+        evaluation_point: &[P::Scalar],
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        if let Some(transcript) =
+            cache.get(&commit_output.commitment, evaluation_point, &fri_params)
+        {
+            debug!("Proof cache hit");
+            return Ok(transcript);
+        }
+
+        let transcript = self.prove(
+            packed_mle,
+            fri_params.clone(),
+            ntt,
+            commit_output,
+            evaluation_point,
+        )?;
+        cache.insert(
+            &commit_output.commitment,
+            evaluation_point,
+            &fri_params,
+            transcript.clone(),
+        );
+        Ok(transcript)
     }
 
-    /// Convert large field MLE to small field MLE representation
-    ///
-    /// Decomposes extension field elements into their base field components.
-    /// This is the inverse operation of `lift_small_to_large_field`.
+    /// Generate an evaluation proof for a point given in Lagrange form
     ///
-    /// # Type Parameters
+    /// The FRI verifier consumes coordinate-form points, so the weights are first mapped
+    /// back with [`Self::lagrange_weights_to_point`] and the proof is generated exactly as
+    /// in [`Self::prove`]. The recovered point is returned alongside the transcript so the
+    /// caller can hand it to `verify_evaluation`; the matching claim is given by
+    /// [`Self::calculate_evaluation_claim_lagrange`].
     ///
-    /// * `F` - Base field type
-    /// * `FE` - Extension field type (must extend `F`)
+    /// # Returns
     ///
-    /// # Arguments
+    /// * `Ok((Vec<P::Scalar>, VerifierTranscript))` - Coordinate-form point and proof transcript
+    /// * `Err(FriVeilError)` - Error if the weights are invalid or proof generation fails
+    pub fn prove_at_lagrange(
+        &self,
+        packed_mle: FieldBuffer<P>,
+        lagrange_weights: &[P::Scalar],
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+        commit_output: &StoredCommitOutput<P>,
+    ) -> Result<(Vec<P::Scalar>, VerifierTranscript<StdChallenger>), FriVeilError> {
+        let evaluation_point = self.lagrange_weights_to_point(lagrange_weights)?;
+        let transcript = self.prove(
+            packed_mle,
+            fri_params,
+            ntt,
+            commit_output,
+            &evaluation_point,
+        )?;
+        Ok((evaluation_point, transcript))
+    }
+
+    /// Verify a serialized evaluation proof, reusing a cached result when available
     ///
-    /// * `large_field_mle` - MLE in extension field
+    /// Consults `cache` with the proof fingerprint, claim, point and parameters before
+    /// running `verify_evaluation`; on a miss the result (accept or reject) is inserted.
+    /// See [`VerifiedProofCache`] for the parameter-scoping caveat.
     ///
     /// # Returns
     ///
-    /// Vector of base field elements (flattened representation)
-    fn large_field_mle_to_small_field_mle<F, FE>(&self, large_field_mle: &[FE]) -> Vec<F>
-    where
-        F: Field,
-        FE: Field + ExtensionField<F>,
-    {
-        large_field_mle
-            .iter()
-            .flat_map(|elm| ExtensionField::<F>::iter_bases(elm))
-            .collect()
+    /// * `Ok(())` - Proof is valid
+    /// * `Err(FriVeilError)` - Proof is invalid or verification failed
+    pub fn verify_evaluation_cached(
+        &self,
+        cache: &mut VerifiedProofCache,
+        proof_bytes: &[u8],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        cache.get_or_verify(
+            proof_bytes,
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+            || {
+                let mut verifier_transcript =
+                    VerifierTranscript::new(StdChallenger::default(), proof_bytes.to_vec());
+                self.verify_evaluation(
+                    &mut verifier_transcript,
+                    evaluation_claim,
+                    evaluation_point,
+                    fri_params,
+                )
+            },
+        )
     }
-}
 
-impl<'a, P, VCS, NTT> FriVeilSampling<P, NTT> for FriVeil<'a, P, VCS, NTT>
-where
-    NTT: AdditiveNTT<Field = B128> + Sync,
-    P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
-    VCS: MerkleTreeScheme<P::Scalar>,
-{
-    /// Decode a Reed-Solomon codeword with error correction for missing points
-    /// This implements proper Reed-Solomon erasure decoding using polynomial interpolation
-    /// Note: Extremely naive algorithm, primarily for soundness guarantee demonstration.
-    /// # Performance
-    /// - When compiled with `--features parallel`, uses rayon for parallel processing
-    /// - When compiled without the parallel feature, uses sequential processing
-    fn reconstruct_codeword_naive(
+    /// Verify a batch of serialized evaluation proofs, collecting every failure
+    ///
+    /// Each entry is `(proof_bytes, evaluation_claim, evaluation_point)` and is checked
+    /// independently with `verify_evaluation` under the shared `fri_params`. Unlike a
+    /// single pass/fail answer, the result records how many proofs passed and why each
+    /// of the others failed.
+    ///
+    /// # Returns
+    ///
+    /// * `BatchResult` - Count of valid proofs and `(index, error)` for each invalid one
+    pub fn verify_evaluation_many(
         &self,
-        corrupted_codeword: &mut [P::Scalar],
-        corrupted_indices: &[usize],
-    ) -> Result<(), String> {
-        let n = corrupted_codeword.len();
-        let domain = (0..corrupted_codeword.len())
-            .map(|i| P::Scalar::from(i as u128))
-            .collect::<Vec<_>>();
-        if corrupted_indices.is_empty() {
-            return Ok(());
-        }
-
-        // Collect known points (x_j, y_j)
-        let known: Vec<(P::Scalar, P::Scalar)> = (0..n)
-            .filter(|i| !corrupted_indices.contains(i))
-            .map(|i| (domain[i], corrupted_codeword[i]))
-            .collect();
-
-        let k = known.len();
-        if k == 0 {
-            return Err("No known points available for reconstruction".into());
-        }
-
-        // For each erased position, interpolate and evaluate
-        #[cfg(feature = "parallel")]
-        {
-            // Parallel version using rayon
-            let reconstructed_values: Vec<(usize, P::Scalar)> = corrupted_indices
-                .par_iter()
-                .map(|&missing| {
-                    debug!("Calculating value for missing index: {}", missing);
-                    let x_e = domain[missing];
-
-                    let mut value = P::Scalar::zero();
-
-                    for j in 0..k {
-                        let (x_j, y_j) = known[j];
-
-                        // Compute L_j(x_e)
-                        let mut l_j = P::Scalar::ONE;
-                        for m in 0..k {
-                            if m == j {
-                                continue;
-                            }
-                            let (x_m, _) = known[m];
-                            l_j = l_j * (x_e - x_m) * (x_j - x_m).invert().unwrap();
-                        }
-
-                        value = value + y_j * l_j;
-                    }
-
-                    debug!(
-                        "Reconstructed value for missing index {}: {:?}",
-                        missing, value
-                    );
-                    (missing, value)
-                })
-                .collect();
-
-            // Apply the reconstructed values to the codeword
-            for (missing, value) in reconstructed_values {
-                corrupted_codeword[missing] = value;
-            }
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        {
-            // Sequential version
-            for &missing in corrupted_indices {
-                debug!("Calculating value for missing index: {}", missing);
-                let x_e = domain[missing];
-
-                let mut value = P::Scalar::zero();
-
-                for j in 0..k {
-                    let (x_j, y_j) = known[j];
-
-                    // Compute L_j(x_e)
-                    let mut l_j = P::Scalar::ONE;
-                    for m in 0..k {
-                        if m == j {
-                            continue;
-                        }
-                        let (x_m, _) = known[m];
-                        l_j = l_j * (x_e - x_m) * (x_j - x_m).invert().unwrap();
-                    }
-
-                    value = value + y_j * l_j;
+        proofs: &[(&[u8], P::Scalar, &[P::Scalar])],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> BatchResult {
+        proofs.iter().enumerate().fold(
+            BatchResult::default(),
+            |mut result, (index, &(proof_bytes, evaluation_claim, evaluation_point))| {
+                let mut verifier_transcript =
+                    VerifierTranscript::new(StdChallenger::default(), proof_bytes.to_vec());
+                match self.verify_evaluation(
+                    &mut verifier_transcript,
+                    evaluation_claim,
+                    evaluation_point,
+                    fri_params,
+                ) {
+                    Ok(()) => result.passed += 1,
+                    Err(e) => result.failed.push((index, e)),
                 }
+                result
+            },
+        )
+    }
 
-                debug!(
-                    "Reconstructed value for missing index {}: {:?}",
-                    missing, value
-                );
-                corrupted_codeword[missing] = value;
-            }
-        }
-
-        Ok(())
+    /// Verify an evaluation proof generated by [`Self::prove_bound`]
+    ///
+    /// Rejects the proof before any FRI work if it was bound to a different context than
+    /// `binding`, e.g. an epoch-5 proof checked under epoch 6. Unbound proofs are only
+    /// accepted with `ProofBinding::new()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid for `binding`
+    /// * `Err(FriVeilError)` - Binding mismatch, or the proof is invalid
+    pub fn verify_evaluation_bound(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        binding: &ProofBinding,
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Any,
+            binding,
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
     }
 
-    /// Verify an evaluation proof for the committed polynomial
+    /// Verify a time-limited proof generated by [`Self::prove_bound`] at time `now`
     ///
-    /// Verifies that a polynomial evaluates to a claimed value at a given point
-    /// using the FRI-based proof in the transcript.
+    /// `binding` is the context published with the commitment, including its
+    /// [`ProofBinding::valid_until`]. The proof is rejected before any transcript work if
+    /// `now` is past the expiry, and otherwise verified as in
+    /// [`Self::verify_evaluation_bound`], so a proof bound to another expiry fails too.
+    ///
+    /// `now` must come from the verifier's own clock: the expiry is only as trustworthy
+    /// as that clock, and a verifier whose clock lags accepts proofs that have expired.
     ///
     /// # Arguments
     ///
     /// * `verifier_transcript` - Transcript containing the proof
+    /// * `binding` - Expected binding, with `valid_until` set
+    /// * `now` - Current time, in the same unit as `valid_until`
     /// * `evaluation_claim` - Claimed evaluation result
     /// * `evaluation_point` - Point at which polynomial was evaluated
     /// * `fri_params` - FRI protocol parameters
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Proof is valid
-    /// * `Err(String)` - Proof is invalid or verification failed
+    /// * `Ok(())` - Proof has not expired and is valid for `binding`
+    /// * `Err(FriVeilError)` - Expired or missing expiry, binding mismatch, or the proof
+    ///   is invalid
+    pub fn verify_evaluation_fresh(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        binding: &ProofBinding,
+        now: u64,
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        binding.check_fresh(now)?;
+        self.verify_evaluation_bound(
+            verifier_transcript,
+            binding,
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Verify a proof from [`Self::prove_at_commitment_point`]
     ///
-    /// # Process
+    /// Rederives the point from `commitment` and checks the proof embeds that root, so
+    /// a proof at any other point is rejected.
     ///
-    /// 1. Extract commitment from transcript
-    /// 2. Run FRI verification protocol
-    /// 3. Check consistency with claimed evaluation
-    fn verify_evaluation(
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid at the commitment's derived point
+    /// * `Err(FriVeilError)` - Root or point mismatch, or the proof is invalid
+    pub fn verify_evaluation_at_commitment_point(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        commitment: [u8; 32],
+        evaluation_claim: P::Scalar,
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        let evaluation_point = self.evaluation_point_from_root(&commitment);
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Root(commitment),
+            &ProofBinding::new(),
+            evaluation_claim,
+            &evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Shared verification path behind the `verify_evaluation*` methods
+    ///
+    /// Checks the proof prefix with [`read_proof_prefix`], then runs FRI verification
+    /// against the commitment root it embeds.
+    fn verify_evaluation_against(
         &self,
         verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        expected_commitment: ExpectedCommitment<'_>,
+        expected_binding: &ProofBinding,
         evaluation_claim: P::Scalar,
         evaluation_point: &[P::Scalar],
         fri_params: &FRIParams<P::Scalar>,
-    ) -> Result<(), String> {
-        // Extract commitment from transcript
-        let retrieved_codeword_commitment = verifier_transcript
-            .message()
-            .read()
-            .map_err(|e| e.to_string())?;
+    ) -> Result<(), FriVeilError> {
+        let retrieved_codeword_commitment = read_proof_prefix(
+            verifier_transcript,
+            &expected_commitment,
+            expected_binding,
+            evaluation_point,
+            fri_params,
+        )
+        .map_err(|(_, error)| error)?;
 
         let merkle_prover_scheme = self.merkle_prover.scheme().clone();
         verify(
             verifier_transcript,
             evaluation_claim,
             evaluation_point,
-            retrieved_codeword_commitment,
+            retrieved_codeword_commitment.into(),
             fri_params,
             &merkle_prover_scheme,
         )
-        .map_err(|e| e.to_string())
+        .map_err(FriVeilError::binius)
     }
 
-    /// Generate a Merkle inclusion proof for a specific codeword position
+    /// Commit to a blob body together with a separately verifiable header
     ///
-    /// Creates a proof that a value at a given index is part of the committed
-    /// codeword. This is used for data availability sampling.
+    /// The body is committed as in [`Self::commit`]; the combined root published for the
+    /// blob is [`header_body_root`] over the [`header_digest`] and the body root. The
+    /// header can then be checked with [`Self::verify_header`] from two hashes, without
+    /// the body or any FRI work, while body evaluations are proven with
+    /// [`Self::prove_body`] and checked against the same combined root.
     ///
     /// # Arguments
     ///
-    /// * `committed` - Merkle tree commitment structure
-    /// * `index` - Position in the codeword to prove
+    /// * `header` - Blob metadata, committed by hash only
+    /// * `body_mle` - Packed body MLE
+    /// * `fri_params` - FRI protocol parameters
+    /// * `ntt` - NTT instance for Reed-Solomon encoding
     ///
     /// # Returns
     ///
-    /// * `Ok(VerifierTranscript)` - Transcript containing the inclusion proof
-    /// * `Err(String)` - Error generating the proof
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let proof = friveil.inclusion_proof(&commit_output.committed, 42)?;
-    /// ```
-    fn inclusion_proof(
+    /// * `Ok((CommitOutput, [u8; 32]))` - Body commitment and the combined root
+    /// * `Err(FriVeilError)` - Body commitment failed
+    pub fn commit_with_header(
         &self,
-        committed: &<BinaryMerkleTreeProver<
-            P::Scalar,
-            StdDigest,
-            ParallelCompressionAdaptor<StdCompression>,
-        > as MerkleTreeProver<P::Scalar>>::Committed,
-        index: usize,
-    ) -> Result<VerifierTranscript<StdChallenger>, String> {
-        let mut proof_writer = ProverTranscript::new(StdChallenger::default());
-        self.merkle_prover
-            .prove_opening(committed, 0, index, &mut proof_writer.message())
-            .map_err(|e| e.to_string())?;
-
-        let proof_reader = proof_writer.into_verifier();
-
-        Ok(proof_reader)
+        header: &[u8],
+        body_mle: FieldBuffer<P>,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+    ) -> Result<(StoredCommitOutput<P>, [u8; 32]), FriVeilError> {
+        let commit_output = self.commit(body_mle, fri_params, ntt)?;
+        let root = header_body_root(&header_digest(header), &commit_output.commitment);
+        Ok((commit_output, root))
     }
 
-    /// Verify a Merkle inclusion proof for a codeword value
+    /// Open the header of a commitment from [`Self::commit_with_header`]
     ///
-    /// Verifies that a value at a specific index is correctly committed
-    /// in the Merkle tree. This is the verification counterpart to `inclusion_proof`.
+    /// # Returns
+    ///
+    /// * `Ok(HeaderProof)` - The body root, which with the header recomputes the combined root
+    /// * `Err(FriVeilError)` - The body root is not 32 bytes
+    pub fn prove_header<C>(
+        &self,
+        commit_output: &CommitOutput<P, Vec<u8>, C>,
+    ) -> Result<HeaderProof, FriVeilError> {
+        let body_root = Commitment::from_bytes(&commit_output.commitment)?.into();
+        Ok(HeaderProof { body_root })
+    }
+
+    /// Verify `header` against a combined root without touching the body
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `header` is the one committed under `combined_root`
+    /// * `Err(FriVeilError)` - Header or body root mismatch
+    pub fn verify_header(
+        &self,
+        combined_root: [u8; 32],
+        header: &[u8],
+        header_proof: &HeaderProof,
+    ) -> Result<(), FriVeilError> {
+        let root = header_body_root(&header_digest(header), &header_proof.body_root);
+        if !ct_eq(&root, &combined_root) {
+            return Err(FriVeilError::CommitmentMismatch(format!(
+                "Header mismatch: header and body root give {} but expected {}",
+                to_hex(&root),
+                to_hex(&combined_root)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Prove a body evaluation of a commitment from [`Self::commit_with_header`]
+    ///
+    /// Identical to [`Self::prove`] on the body; the proof embeds the body root, which
+    /// [`Self::verify_body`] ties back to the combined root.
+    pub fn prove_body(
+        &self,
+        body_mle: FieldBuffer<P>,
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+        commit_output: &StoredCommitOutput<P>,
+        evaluation_point: &[P::Scalar],
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        self.prove(body_mle, fri_params, ntt, commit_output, evaluation_point)
+    }
+
+    /// Verify a body evaluation proof against a combined root
+    ///
+    /// The body root embedded in the proof is combined with `header_digest` and compared
+    /// against `combined_root` before any FRI work is done.
     ///
     /// # Arguments
     ///
-    /// * `verifier_transcript` - Transcript containing the inclusion proof
-    /// * `data` - Value(s) to verify
-    /// * `index` - Position in the codeword
-    /// * `fri_params` - FRI parameters (for context)
-    /// * `commitment` - Merkle root commitment (32 bytes)
+    /// * `verifier_transcript` - Transcript from [`Self::prove_body`]
+    /// * `combined_root` - Root returned by [`Self::commit_with_header`]
+    /// * `header_digest` - [`header_digest`] of the committed header
+    /// * `evaluation_claim` - Claimed body evaluation
+    /// * `evaluation_point` - Point at which the body was evaluated
+    /// * `fri_params` - FRI protocol parameters
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Inclusion proof is valid
-    /// * `Err(String)` - Proof is invalid or verification failed
+    /// * `Ok(())` - Proof is valid for the body committed under `combined_root`
+    /// * `Err(FriVeilError)` - Root mismatch, or the proof is invalid
+    pub fn verify_body(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        combined_root: [u8; 32],
+        header_digest: &[u8; 32],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::WithHeader {
+                root: combined_root,
+                header_digest,
+            },
+            &ProofBinding::new(),
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Extract a standalone availability proof for one codeword position
     ///
-    /// # Example
+    /// The proof carries the share value and its serialized Merkle opening, so it can be
+    /// gossiped on its own and checked with [`Self::verify_share_proof`] against the root.
     ///
-    /// ```ignore
-    /// friveil.verify_inclusion_proof(
-    ///     &mut proof,
-    ///     &[value],
-    ///     index,
-    ///     &fri_params,
-    ///     commitment_bytes,
-    /// )?;
-    /// ```
-    fn verify_inclusion_proof(
+    /// # Arguments
+    ///
+    /// * `commit_output` - Output from the commit phase
+    /// * `index` - Codeword position to prove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ShareProof)` - Value and Merkle opening for `index`
+    /// * `Err(FriVeilError)` - Index out of range, or opening generation failed
+    pub fn share_proof(
         &self,
-        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
-        data: &[P::Scalar],
+        commit_output: &StoredCommitOutput<P>,
         index: usize,
-        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<ShareProof, FriVeilError> {
+        check_not_pruned(commit_output)?;
+        let value = commit_output
+            .codeword
+            .get(index >> P::LOG_WIDTH)
+            .map(|packed| packed.get(index % P::WIDTH))
+            .ok_or_else(|| {
+                FriVeilError::QueryOutOfRange(format!(
+                    "Share index {} out of range for codeword of length {}",
+                    index,
+                    commit_output.codeword.len() << P::LOG_WIDTH
+                ))
+            })?;
+        let opening = self.inclusion_proof(&commit_output.committed, index)?;
+
+        Ok(ShareProof {
+            value,
+            merkle_path: transcript_bytes(&opening),
+        })
+    }
+
+    /// Verify a standalone availability proof against a commitment root
+    ///
+    /// # Arguments
+    ///
+    /// * `commitment` - Merkle root (32 bytes)
+    /// * `index` - Codeword position the proof claims
+    /// * `share_proof` - Proof produced by [`Self::share_proof`]
+    /// * `fri_params` - FRI parameters (for the tree depth)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The share value is committed at `index` under `commitment`
+    /// * `Err(FriVeilError)` - The opening is invalid
+    pub fn verify_share_proof(
+        &self,
         commitment: [u8; 32],
-    ) -> Result<(), String> {
-        let tree_depth = fri_params.rs_code().log_len();
-        self.merkle_prover
-            .scheme()
-            .verify_opening(
-                index,
-                data,
-                0,
-                tree_depth,
-                &[commitment.into()],
-                &mut verifier_transcript.message(),
-            )
-            .map_err(|e| e.to_string())
+        index: usize,
+        share_proof: &ShareProof,
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        let mut verifier_transcript =
+            VerifierTranscript::new(StdChallenger::default(), share_proof.merkle_path.clone());
+        self.verify_inclusion_proof(
+            &mut verifier_transcript,
+            &[share_proof.value],
+            index,
+            fri_params,
+            commitment,
+        )
     }
 
-    /// Decode a Reed-Solomon encoded codeword back to original data
+    /// Sample `num_samples` codeword positions across a whole block of commitments
     ///
-    /// Applies inverse Reed-Solomon transformation to recover the original
-    /// data from the encoded codeword. This is the inverse of `encode_codeword`.
+    /// Positions are derived from a hash of all roots and the verifier's `nonce` (see
+    /// [`block_sample_positions`]), so the prover cannot choose them and the verifier
+    /// recomputes them from the roots and its own nonce. The nonce must be fresh per
+    /// request and only revealed once the block is committed: positions fixed by the
+    /// roots alone are known as soon as the block is, so a prover could keep just the
+    /// sampled shares, discard the rest, and still pass every check. The proof only carries one [`ShareProof`] per sample, in sample order, with
+    /// no indices, so a block-level check costs `num_samples` openings instead of
+    /// `num_samples` per commitment.
     ///
     /// # Arguments
     ///
-    /// * `codeword` - Reed-Solomon encoded codeword
-    /// * `fri_params` - FRI parameters containing RS code configuration
-    /// * `ntt` - NTT instance for efficient decoding
+    /// * `commits` - Commitments of the block, all under `fri_params`
+    /// * `num_samples` - Total number of positions to sample across the block
+    /// * `nonce` - Challenge chosen by the verifier for this request
+    /// * `fri_params` - FRI parameters shared by every commitment
     ///
     /// # Returns
     ///
-    /// * `Ok(Vec<P::Scalar>)` - Decoded original data
-    /// * `Err(String)` - Error message if decoding fails
+    /// * `Ok(BlockSamplingProof)` - Share proofs for every sampled position
+    /// * `Err(FriVeilError)` - A commitment is malformed or opening generation failed
+    pub fn prove_block_sampling(
+        &self,
+        commits: &[StoredCommitOutput<P>],
+        num_samples: usize,
+        nonce: &[u8; 32],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<BlockSamplingProof, FriVeilError> {
+        let roots = commits
+            .iter()
+            .map(|commit_output| {
+                Commitment::from_bytes(&commit_output.commitment).map(<[u8; 32]>::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let codeword_len = 1usize << fri_params.rs_code().log_len();
+
+        let shares = block_sample_positions(&roots, nonce, codeword_len, num_samples)?
+            .into_iter()
+            .map(|(commitment, index)| self.share_proof(&commits[commitment], index))
+            .collect::<Result<_, _>>()?;
+        Ok(BlockSamplingProof { shares })
+    }
+
+    /// Verify a proof from [`Self::prove_block_sampling`] against the block's roots
     ///
-    /// # Process
+    /// # Arguments
     ///
-    /// 1. Calculate expected output length
-    /// 2. Apply inverse NTT transformation
-    /// 3. Trim to original data size (remove redundancy)
+    /// * `roots` - Commitment roots of the block, in the order they were proven
+    /// * `num_samples` - Number of samples the verifier requires
+    /// * `nonce` - Challenge the verifier sent with the request
+    /// * `proof` - Block sampling proof
+    /// * `fri_params` - FRI parameters shared by every commitment
     ///
-    /// # Example
+    /// # Returns
     ///
-    /// ```ignore
-    /// let decoded = friveil.decode_codeword(&encoded, fri_params, &ntt)?;
-    /// assert_eq!(decoded, original_data);
-    /// ```
-    fn decode_codeword(
+    /// * `Ok(())` - Every sampled share is committed at its derived position
+    /// * `Err(FriVeilError)` - Wrong number of samples, or an opening is invalid
+    pub fn verify_block_sampling(
         &self,
-        codeword: &[P::Scalar],
+        roots: &[[u8; 32]],
+        num_samples: usize,
+        nonce: &[u8; 32],
+        proof: &BlockSamplingProof,
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        if proof.shares.len() != num_samples {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Block sampling proof has {} samples but {} are required",
+                proof.shares.len(),
+                num_samples
+            )));
+        }
+        let codeword_len = 1usize << fri_params.rs_code().log_len();
+
+        for (sample, ((commitment, index), share_proof)) in
+            block_sample_positions(roots, nonce, codeword_len, num_samples)?
+                .into_iter()
+                .zip(&proof.shares)
+                .enumerate()
+        {
+            self.verify_share_proof(roots[commitment], index, share_proof, fri_params)
+                .map_err(|e| {
+                    FriVeilError::CommitmentMismatch(format!(
+                        "Sample {} (commitment {}, index {}) failed: {}",
+                        sample, commitment, index, e
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Encode data using Reed-Solomon code with NTT
+    ///
+    /// This is a helper function to observe NTT encoding behavior outside
+    /// the `commit` function. Applies Reed-Solomon encoding to expand data
+    /// with redundancy for error correction.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Input data to encode
+    /// * `fri_params` - FRI parameters containing Reed-Solomon code configuration
+    /// * `ntt` - NTT instance for efficient encoding
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<P::Scalar>)` - Encoded codeword with redundancy
+    /// * `Err(FriVeilError)` - Error message if encoding fails
+    ///
+    /// # Note
+    ///
+    /// This function is marked `#[allow(dead_code)]` as it's primarily used
+    /// for testing and debugging. The `commit` function handles encoding internally.
+    #[allow(dead_code)]
+    pub fn encode_codeword(
+        &self,
+        data: &[P::Scalar],
         fri_params: FRIParams<P::Scalar>,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
-    ) -> Result<Vec<P::Scalar>, String> {
+    ) -> Result<Vec<P::Scalar>, FriVeilError> {
         let rs_code = fri_params.rs_code();
-        let len = 1 << (rs_code.log_len() + fri_params.log_batch_size() - P::LOG_WIDTH);
+        let len = 1
+            << (rs_code.log_dim() + fri_params.log_batch_size() - P::LOG_WIDTH
+                + rs_code.log_inv_rate());
 
-        let mut decoded = Vec::with_capacity(len);
-        self.decode_batch(
-            rs_code.log_len(),
-            rs_code.log_inv_rate(),
-            fri_params.log_batch_size(),
-            ntt,
-            codeword.as_ref(),
-            decoded.spare_capacity_mut(),
-        )
-        .map_err(|e| e.to_string())?;
+        let mut encoded = Vec::with_capacity(len);
+
+        rs_code
+            .encode_batch(
+                ntt,
+                data.as_ref(),
+                encoded.spare_capacity_mut(),
+                fri_params.log_batch_size(),
+            )
+            .map_err(FriVeilError::binius)?;
 
         unsafe {
-            // Safety: decode_batch guarantees all elements are initialized on success
-            decoded.set_len(len);
+            // Safety: encode_ext_batch guarantees all elements are initialized on success
+            encoded.set_len(len);
         }
 
-        // Trim to original data size (remove redundancy)
-        let trim_len = 1 << (rs_code.log_dim() + fri_params.log_batch_size() - P::LOG_WIDTH);
-        decoded.resize(trim_len, P::Scalar::zero());
-        Ok(decoded)
+        Ok(encoded)
     }
 
-    /// Extract commitment from verifier transcript
+    /// Lift elements from a small field to a large extension field
     ///
-    /// Helper function to read the commitment bytes from a transcript.
-    /// This is used internally for verification workflows.
+    /// Converts field elements from a base field `F` to an extension field `FE`.
+    /// This is used for efficient computation in the extension field.
     ///
-    /// # Arguments
+    /// # Type Parameters
     ///
-    /// * `verifier_transcript` - Transcript containing the commitment
+    /// * `F` - Base field type
+    /// * `FE` - Extension field type (must extend `F`)
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// * `Ok(Vec<u8>)` - Commitment bytes
-    /// * `Err(String)` - Error reading from transcript
+    /// * `small_field_elms` - Elements in the base field
     ///
-    /// # Note
+    /// # Returns
     ///
-    /// Marked as `#[allow(dead_code)]` as it's currently unused but
-    /// may be useful for future transcript manipulation.
-    #[allow(dead_code)]
-    fn extract_commitment(
-        &self,
-        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
-    ) -> Result<Vec<u8>, String> {
-        verifier_transcript
-            .message()
-            .read()
-            .map_err(|e| e.to_string())
+    /// Vector of elements lifted to the extension field
+    #[must_use]
+    pub fn lift_small_to_large_field<F, FE>(&self, small_field_elms: &[F]) -> Vec<FE>
+    where
+        F: Field,
+        FE: Field + ExtensionField<F>,
+    {
+        small_field_elms.iter().map(|&elm| FE::from(elm)).collect()
     }
 
-    /// Low-level batch decoding using inverse NTT
+    /// Convert large field MLE to small field MLE representation
     ///
-    /// Performs the actual Reed-Solomon decoding operation using inverse NTT.
-    /// This is called by `decode_codeword` and handles the core transformation.
+    /// Decomposes extension field elements into their base field components.
+    /// This is the inverse operation of `lift_small_to_large_field`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - Base field type
+    /// * `FE` - Extension field type (must extend `F`)
     ///
     /// # Arguments
     ///
-    /// * `log_len` - Logarithm of codeword length
-    /// * `log_inv` - Logarithm of inverse rate (redundancy factor)
-    /// * `log_batch_size` - Logarithm of batch size
-    /// * `ntt` - NTT instance for transformation
-    /// * `data` - Input codeword data
-    /// * `output` - Uninitialized output buffer
+    /// * `large_field_mle` - MLE in extension field
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Decoding successful, output buffer is initialized
-    /// * `Err(String)` - Decoding failed
+    /// Vector of base field elements (flattened representation)
+    fn large_field_mle_to_small_field_mle<F, FE>(&self, large_field_mle: &[FE]) -> Vec<F>
+    where
+        F: Field,
+        FE: Field + ExtensionField<F>,
+    {
+        large_field_mle
+            .iter()
+            .flat_map(|elm| ExtensionField::<F>::iter_bases(elm))
+            .collect()
+    }
+}
+
+impl FriVeilDefault {
+    /// Verify an evaluation proof whose claim and point arrive as raw bytes
     ///
-    /// # Safety
+    /// For callers holding wire data, such as a zkVM guest or FFI consumer: the point
+    /// is decoded with [`decode_evaluation_point`], so misframed coordinates are
+    /// rejected before any transcript work, and its length is then checked against
+    /// `fri_params` as in [`FriVeilSampling::verify_evaluation`].
     ///
-    /// On success, guarantees that all elements in `output` are properly initialized.
+    /// # Arguments
     ///
-    /// # Implementation Details
+    /// * `verifier_transcript` - Transcript containing the proof
+    /// * `evaluation_claim` - Claimed evaluation, 16 little-endian bytes
+    /// * `evaluation_point` - Point, 16 little-endian bytes per coordinate
+    /// * `fri_params` - FRI protocol parameters
     ///
-    /// 1. Validates input dimensions
-    /// 2. Copies data to output buffer
-    /// 3. Applies inverse NTT with appropriate skip parameters
-    /// 4. Handles both packed and unpacked field representations
-    fn decode_batch(
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid
+    /// * `Err(FriVeilError)` - Malformed point, or the proof is invalid
+    pub fn verify_evaluation_raw(
         &self,
-        log_len: usize,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        evaluation_claim: [u8; 16],
+        evaluation_point: &[u8],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<(), FriVeilError> {
+        let evaluation_point = decode_evaluation_point(evaluation_point)?;
+        self.verify_evaluation(
+            verifier_transcript,
+            B128::from(u128::from_le_bytes(evaluation_claim)),
+            &evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Generate an evaluation proof against a commitment fetched from `store` by `root`
+    ///
+    /// Looks up the [`CommitOutput`] committed as `root` and proves exactly as
+    /// [`FriVeil::prove`] does with it in hand, so the proof is byte-identical. The caller
+    /// still supplies the packed MLE, since stores keep only the commit output.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - Store the commitment was inserted into
+    /// * `root` - Commitment root to prove against
+    /// * `packed_mle` - The committed packed MLE
+    /// * `fri_params` - FRI parameters the commitment was made under
+    /// * `ntt` - NTT instance for encoding
+    /// * `evaluation_point` - Point at which to prove the evaluation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Transcript containing the proof
+    /// * `Err(FriVeilError)` - `store` holds no commitment for `root`, or proof generation
+    ///   fails
+    pub fn prove_from_store<S: CommitmentStore + ?Sized>(
+        &self,
+        store: &S,
+        root: &[u8],
+        packed_mle: FieldBuffer<B128>,
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+        evaluation_point: &[B128],
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        let commit_output = store.get(root).ok_or_else(|| {
+            FriVeilError::CommitmentMismatch(format!(
+                "No stored commitment for root {}",
+                to_hex(root)
+            ))
+        })?;
+        self.prove(
+            packed_mle,
+            fri_params,
+            ntt,
+            &commit_output,
+            evaluation_point,
+        )
+    }
+
+    /// Commit to a fixed-size DA cell of exactly `2^log_cell_bytes` bytes
+    ///
+    /// Unlike [`FriVeil::commit`] on the output of `bytes_to_packed_mle`, inputs are never
+    /// padded: every accepted cell fills its MLE exactly, so all cells of one size commit
+    /// under identical parameters and no two byte strings of different lengths can share a
+    /// commitment.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Cell contents
+    /// * `log_cell_bytes` - Logarithm of the cell size; at least 4 (one B128 element)
+    /// * `fri_params` - FRI parameters for `2^(log_cell_bytes - 4)` elements
+    /// * `ntt` - NTT instance for encoding
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CommitOutput)` - Same output as [`FriVeil::commit`]
+    /// * `Err(FriVeilError)` - The cell is over- or undersized, or commitment failed
+    pub fn commit_fixed_cell(
+        &self,
+        bytes: &[u8],
+        log_cell_bytes: usize,
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    ) -> Result<StoredCommitOutput, FriVeilError> {
+        let log_element_bytes = BYTES_PER_ELEMENT.ilog2() as usize;
+        if log_cell_bytes < log_element_bytes || log_cell_bytes >= usize::BITS as usize {
+            return Err(FriVeilError::InvalidInput(format!(
+                "log_cell_bytes {} must be between {} and {}",
+                log_cell_bytes,
+                log_element_bytes,
+                usize::BITS - 1
+            )));
+        }
+        let cell_bytes = 1usize << log_cell_bytes;
+        if bytes.len() != cell_bytes {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Cell must be exactly {} bytes, got {}",
+                cell_bytes,
+                bytes.len()
+            )));
+        }
+
+        let packed_mle_values = Utils::<B128>::new().bytes_to_packed_mle(bytes)?;
+        self.commit(packed_mle_values.packed_mle, fri_params, ntt)
+    }
+
+    /// Commit to a codeword that was already Reed-Solomon encoded upstream
+    ///
+    /// The codeword must have the length of `fri_params`' RS code and be a valid codeword
+    /// of it, i.e. the encoding of its own decoded message; anything else is rejected, so
+    /// a mismatched upstream encoding cannot produce a root. The root then equals
+    /// [`FriVeil::commit`] on the same data.
+    ///
+    /// # Note
+    ///
+    /// The Merkle tree consumed by `prove` is built inside the binius PCS prover from the
+    /// message, so the codeword is decoded and re-encoded rather than hashed directly.
+    /// This saves no encoding work, but lets an erasure-coding pipeline hand over its
+    /// codeword as-is and checks the pipeline agrees with this crate's encoding.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CommitOutput)` - Commitment whose codeword equals `codeword`
+    /// * `Err(FriVeilError)` - Wrong length, or `codeword` is not a codeword of the RS code
+    pub fn commit_from_codeword(
+        &self,
+        codeword: &[B128],
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    ) -> Result<StoredCommitOutput, FriVeilError> {
+        let expected_len = 1usize << (fri_params.rs_code().log_len() + fri_params.log_batch_size());
+        if codeword.len() != expected_len {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Codeword length {} does not match the RS code length {}",
+                codeword.len(),
+                expected_len
+            )));
+        }
+
+        let message = self.decode_codeword(codeword, fri_params.clone(), ntt)?;
+        let packed_mle =
+            FieldBuffer::<B128>::from_values(&message).map_err(FriVeilError::binius)?;
+        let commit_output = self.commit(packed_mle, fri_params, ntt)?;
+        if commit_output.codeword != codeword {
+            return Err(FriVeilError::InvalidInput(
+                "Invalid codeword: not a Reed-Solomon encoding of its message".to_string(),
+            ));
+        }
+        Ok(commit_output)
+    }
+
+    /// Commit to many same-sized blobs under one FRI context
+    ///
+    /// Every MLE is checked against `fri_params` before anything is committed, so a
+    /// mis-sized blob fails the whole batch instead of leaving a partial result. The
+    /// Reed-Solomon message of `fri_params` has `rs_code().log_dim()` elements (its
+    /// `log_len()` is the codeword length, `log_inv_rate` larger), so that is the length
+    /// each packed MLE must have. The same `ntt` and parameters are reused for every
+    /// blob, and each commitment equals [`FriVeil::commit`] on that blob alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `mles` - Packed MLEs, all of the length `fri_params` was built for
+    /// * `fri_params` - FRI parameters shared by every commitment
+    /// * `ntt` - NTT instance shared by every commitment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<CommitOutput>)` - One commitment per MLE, in input order
+    /// * `Err(FriVeilError)` - An MLE has the wrong length, or commitment failed
+    pub fn commit_batch(
+        &self,
+        mles: &[PackedMLE<B128>],
+        fri_params: &FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    ) -> Result<Vec<StoredCommitOutput>, FriVeilError> {
+        let expected_log_len = fri_params.rs_code().log_dim() + fri_params.log_batch_size();
+        if let Some((index, mle)) = mles
+            .iter()
+            .enumerate()
+            .find(|(_, mle)| mle.packed_mle.log_len() != expected_log_len)
+        {
+            return Err(FriVeilError::InvalidLogLen(format!(
+                "MLE {} has log length {} but the FRI message log length is {}",
+                index,
+                mle.packed_mle.log_len(),
+                expected_log_len
+            )));
+        }
+
+        mles.iter()
+            .map(|mle| self.commit(mle.packed_mle.clone(), fri_params.clone(), ntt))
+            .collect()
+    }
+
+    /// Commit to a list of blob roots, aggregating them into one commitment
+    ///
+    /// Each 32-byte root is packed as two consecutive B128 elements (root `j` occupies
+    /// elements `2j` and `2j + 1`), zero-padded to a power of two, and committed like any
+    /// other data; this matches [`Utils::records_to_packed_mle`] on 32-byte records.
+    /// Membership of a single root is then proven with
+    /// [`Self::prove_root_inclusion`]. `self` and `fri_params` must be sized for
+    /// `32 * roots.len()` bytes of data.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((PackedMLE, CommitOutput))` - The packed roots, needed for proving, and the
+    ///   aggregate commitment
+    /// * `Err(FriVeilError)` - No roots, or commitment failed
+    pub fn commit_roots(
+        &self,
+        roots: &[[u8; 32]],
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    ) -> Result<(PackedMLE<B128>, StoredCommitOutput), FriVeilError> {
+        if roots.is_empty() {
+            return Err(FriVeilError::InvalidInput(
+                "Cannot aggregate zero roots".to_string(),
+            ));
+        }
+        let packed_roots = Utils::<B128>::new().bytes_to_packed_mle(roots.as_flattened())?;
+        let commit_output = self.commit(packed_roots.packed_mle.clone(), fri_params, ntt)?;
+        Ok((packed_roots, commit_output))
+    }
+
+    /// Evaluation point used to prove record `index` of a commitment
+    ///
+    /// Records of `record_size` bytes occupy aligned slots of
+    /// [`Utils::record_slot_elements`] elements, as laid out by
+    /// [`Utils::records_to_packed_mle`]. The element variables above the slot are fixed
+    /// to the bits of `index`, so only record `index` contributes to the evaluation. The
+    /// 7 bit variables and the variables inside the slot are derived from a hash of
+    /// `commitment`, `index` and `record_size`, after the commitment is fixed, so by
+    /// Schwartz-Zippel a different record yields a different claim except with
+    /// negligible probability.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<B128>)` - Point with `n_vars` coordinates
+    /// * `Err(FriVeilError)` - Empty records, or `index` does not fit `n_vars`
+    pub fn record_inclusion_point(
+        &self,
+        commitment: &[u8; 32],
+        index: usize,
+        record_size: usize,
+    ) -> Result<Vec<B128>, FriVeilError> {
+        let random_vars =
+            <B128 as ExtensionField<B1>>::LOG_DEGREE + log_record_elements(record_size)?;
+        let index_vars = self.n_vars.checked_sub(random_vars).ok_or_else(|| {
+            FriVeilError::InvalidLogLen(format!(
+                "n_vars {} is too small to hold records of {} bytes",
+                self.n_vars, record_size
+            ))
+        })?;
+        if index_vars < usize::BITS as usize && index >> index_vars != 0 {
+            return Err(FriVeilError::QueryOutOfRange(format!(
+                "Record index {} out of range for a commitment with {} index variables",
+                index, index_vars
+            )));
+        }
+
+        let seed: [u8; 32] = Sha256::new()
+            .chain_update(b"friveil-record-inclusion")
+            .chain_update(commitment)
+            .chain_update((index as u64).to_le_bytes())
+            .chain_update((record_size as u64).to_le_bytes())
+            .finalize()
+            .into();
+        let mut point = expand_point(&seed, random_vars);
+        point.extend((0..index_vars).map(|bit| {
+            if bit < usize::BITS as usize && (index >> bit) & 1 == 1 {
+                B128::ONE
+            } else {
+                B128::ZERO
+            }
+        }));
+        Ok(point)
+    }
+
+    /// Evaluation claim a proof of record inclusion must match at `evaluation_point`
+    ///
+    /// Depends only on `record`, since `evaluation_point` from
+    /// [`Self::record_inclusion_point`] selects a single record slot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(B128)` - Expected claim
+    /// * `Err(FriVeilError)` - Empty record, or `evaluation_point` is too short
+    pub fn record_inclusion_claim(
+        &self,
+        record: &[u8],
+        evaluation_point: &[B128],
+    ) -> Result<B128, FriVeilError> {
+        let random_vars =
+            <B128 as ExtensionField<B1>>::LOG_DEGREE + log_record_elements(record.len())?;
+        let local_point = evaluation_point.get(..random_vars).ok_or_else(|| {
+            FriVeilError::ParameterMismatch(
+                "Evaluation point is too short for a record inclusion claim".to_string(),
+            )
+        })?;
+        let record_elements = Utils::<B128>::new().bytes_to_packed_mle(record)?;
+        self.calculate_evaluation_claim(&record_elements.packed_values, local_point)
+    }
+
+    /// Prove that record `index` of `record_size` bytes is part of a commitment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Evaluation proof at [`Self::record_inclusion_point`]
+    /// * `Err(FriVeilError)` - Index out of range, or proof generation failed
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_record_inclusion(
+        &self,
+        packed_mle: &PackedMLE<B128>,
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+        commit_output: &StoredCommitOutput,
+        index: usize,
+        record_size: usize,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        let commitment: [u8; 32] = Commitment::from_bytes(&commit_output.commitment)?.into();
+        let evaluation_point = self.record_inclusion_point(&commitment, index, record_size)?;
+        self.prove(
+            packed_mle.packed_mle.clone(),
+            fri_params,
+            ntt,
+            commit_output,
+            &evaluation_point,
+        )
+    }
+
+    /// Verify that `record` is the `index`-th record under `commitment`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `record` is included at `index`
+    /// * `Err(FriVeilError)` - Wrong record or index, or the proof is invalid
+    pub fn verify_record_inclusion(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        commitment: [u8; 32],
+        index: usize,
+        record: &[u8],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<(), FriVeilError> {
+        let evaluation_point = self.record_inclusion_point(&commitment, index, record.len())?;
+        let evaluation_claim = self.record_inclusion_claim(record, &evaluation_point)?;
+        self.verify_evaluation_with_root(
+            verifier_transcript,
+            commitment,
+            evaluation_claim,
+            &evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Evaluation point used to prove root `index` of an aggregate
+    ///
+    /// Roots are 32-byte records, see [`Self::record_inclusion_point`].
+    pub fn root_inclusion_point(
+        &self,
+        aggregate_root: &[u8; 32],
+        index: usize,
+    ) -> Result<Vec<B128>, FriVeilError> {
+        self.record_inclusion_point(aggregate_root, index, 32)
+    }
+
+    /// Prove that root `index` is part of an aggregate from [`Self::commit_roots`]
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Evaluation proof at [`Self::root_inclusion_point`]
+    /// * `Err(FriVeilError)` - Index out of range, or proof generation failed
+    pub fn prove_root_inclusion(
+        &self,
+        packed_roots: &PackedMLE<B128>,
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+        commit_output: &StoredCommitOutput,
+        index: usize,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        self.prove_record_inclusion(packed_roots, fri_params, ntt, commit_output, index, 32)
+    }
+
+    /// Verify that `root` is the `index`-th root under `aggregate_root`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - `root` is included at `index`
+    /// * `Err(FriVeilError)` - Wrong root or index, or the proof is invalid
+    pub fn verify_root_inclusion(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        aggregate_root: [u8; 32],
+        index: usize,
+        root: &[u8; 32],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_record_inclusion(verifier_transcript, aggregate_root, index, root, fri_params)
+    }
+
+    /// Export an [`AuditRecord`] of committing `data` and proving it at `evaluation_point`
+    ///
+    /// Packs `data` into its MLE, computes the claim and proof from the given commitment
+    /// and records them with a hash of `data` and the parameters, so an auditor holding
+    /// the record can re-verify with [`AuditRecord::replay`] and check the input with
+    /// [`AuditRecord::check_data`]. Deriving the MLE here keeps the recorded data hash
+    /// and the proven MLE from describing different inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Committed input bytes
+    /// * `fri_params` - FRI protocol parameters
+    /// * `ntt` - NTT instance
+    /// * `commit_output` - Commitment to `data`
+    /// * `evaluation_point` - Point to prove at
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AuditRecord)` - Record with its digest filled in
+    /// * `Err(FriVeilError)` - `data` can't be packed, or claim or proof generation failed
+    pub fn audit_record(
+        &self,
+        data: &[u8],
+        fri_params: FRIParams<B128>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+        commit_output: &StoredCommitOutput,
+        evaluation_point: &[B128],
+    ) -> Result<AuditRecord, FriVeilError> {
+        let packed_mle = Utils::<B128>::new().bytes_to_packed_mle(data)?;
+        let evaluation_claim =
+            self.calculate_evaluation_claim(&packed_mle.packed_values, evaluation_point)?;
+        let proof = self.prove_to_bytes(
+            packed_mle.packed_mle,
+            fri_params,
+            ntt,
+            commit_output,
+            evaluation_point,
+        )?;
+
+        let mut record = AuditRecord {
+            data_hash: Sha256::digest(data).into(),
+            log_inv_rate: self.log_inv_rate,
+            num_test_queries: self.num_test_queries,
+            n_vars: self.n_vars,
+            evaluation_point: evaluation_point.iter().map(|&x| u128::from(x)).collect(),
+            evaluation_claim: u128::from(evaluation_claim),
+            commitment: Commitment::from_bytes(&commit_output.commitment)?.into(),
+            proof,
+            record_digest: [0; 32],
+        };
+        record.record_digest = record.compute_digest();
+        Ok(record)
+    }
+
+    /// Extract [`ShareProof`]s for many codeword positions at once
+    ///
+    /// Each proof equals [`Self::share_proof`] for its index, and results keep the order of
+    /// `indices`. With the `parallel` feature the positions are split across the rayon
+    /// thread pool; the output does not depend on the number of threads.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ShareProof>)` - One proof per entry of `indices`
+    /// * `Err(FriVeilError)` - An index is out of range, or opening generation failed
+    pub fn share_proofs(
+        &self,
+        commit_output: &StoredCommitOutput,
+        indices: &[usize],
+    ) -> Result<Vec<ShareProof>, FriVeilError> {
+        check_not_pruned(commit_output)?;
+
+        #[cfg(feature = "parallel")]
+        let indices = indices.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let indices = indices.iter();
+
+        indices
+            .map(|&index| self.share_proof(commit_output, index))
+            .collect()
+    }
+
+    /// Re-run verification against `expected` and `expected_binding` and report where it
+    /// diverged
+    ///
+    /// The proof prefix is read by the same stage-by-stage reader the `verify_evaluation*`
+    /// methods use, so each stage is reported with its context: point length, header,
+    /// binding, point digest, embedded commitment, then the PCS proof against that
+    /// commitment. Only the first divergence is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript reconstructed from the proof bytes
+    /// * `expected` - Commitment the proof must embed, as passed by the matching
+    ///   `verify_evaluation*` method
+    /// * `expected_binding` - Binding the proof must carry, e.g. [`ProofBinding::new`]
+    ///   for a proof from plain `prove`
+    /// * `evaluation_claim` - Claimed evaluation
+    /// * `evaluation_point` - Point being verified
+    /// * `fri_params` - FRI protocol parameters
+    ///
+    /// # Note
+    ///
+    /// Inside the PCS proof the binius verifier reports a single error for the whole
+    /// sumcheck and FRI run, without the failing query index or codeword position, so
+    /// PCS failures are explained with that error and the commitment they were checked
+    /// against.
+    pub fn explain_failure(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        expected: ExpectedCommitment<'_>,
+        expected_binding: &ProofBinding,
+        evaluation_claim: B128,
+        evaluation_point: &[B128],
+        fri_params: &FRIParams<B128>,
+    ) -> FailureExplanation {
+        let commitment = match read_proof_prefix(
+            verifier_transcript,
+            &expected,
+            expected_binding,
+            evaluation_point,
+            fri_params,
+        ) {
+            Ok(commitment) => commitment,
+            Err((stage, error)) => {
+                let detail = error.to_string();
+                return match stage {
+                    PrefixStage::Header => FailureExplanation::Header { detail },
+                    PrefixStage::Binding => FailureExplanation::Binding { detail },
+                    PrefixStage::EvaluationPoint => FailureExplanation::EvaluationPoint { detail },
+                    PrefixStage::Truncated => FailureExplanation::Truncated { detail },
+                    PrefixStage::Commitment => FailureExplanation::Commitment { detail },
+                };
+            }
+        };
+
+        let pcs_proof_len = verifier_transcript.message().buffer().remaining();
+        match verify(
+            verifier_transcript,
+            evaluation_claim,
+            evaluation_point,
+            commitment.into(),
+            fri_params,
+            self.merkle_prover.scheme(),
+        ) {
+            Ok(()) => FailureExplanation::Verified,
+            Err(e) => FailureExplanation::Pcs {
+                commitment,
+                pcs_proof_len,
+                detail: e.to_string(),
+            },
+        }
+    }
+
+    /// Bytes of shares a client must download to reconstruct the data
+    ///
+    /// Composes the reconstruction threshold of `fri_params`' Reed-Solomon code, any
+    /// `2^log_dim` distinct shares as checked by [`Self::coverage`], with the share size
+    /// of [`BYTES_PER_ELEMENT`] bytes per codeword position and batch element.
+    ///
+    /// The erasure rate is the code's `log_inv_rate`: it sets how many of the
+    /// `2^log_len` shares may be lost, but never lowers the number that must be
+    /// received, so the result equals the padded data size at any rate. `log_num_shares`
+    /// only splits NTT work across threads and doesn't change the share layout, so it
+    /// doesn't enter either. Merkle openings needed to authenticate the shares, and
+    /// duplicates from sampling with replacement, are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `fri_params` - FRI parameters the data was committed with
+    #[must_use]
+    pub fn reconstruction_bytes(&self, fri_params: &FRIParams<B128>) -> usize {
+        let share_bytes = BYTES_PER_ELEMENT << fri_params.log_batch_size();
+        share_bytes << fri_params.rs_code().log_dim()
+    }
+
+    /// Report whether the held codeword shares are enough to recover the data
+    ///
+    /// Reed-Solomon is MDS, so any `2^log_dim` distinct codeword positions determine the
+    /// whole codeword, which `reconstruct_codeword_naive` then fills in. The encoding is
+    /// not systematic: every data element depends on all of them, so fewer shares
+    /// recover no byte range at all rather than a partial one. Duplicate indices count
+    /// once.
+    ///
+    /// # Arguments
+    ///
+    /// * `share_indices` - Codeword positions of the shares held
+    /// * `fri_params` - FRI parameters the data was committed with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CoverageReport)` - Held and required share counts and the recoverable range
+    /// * `Err(FriVeilError)` - A share index is outside the codeword
+    pub fn coverage(
+        &self,
+        share_indices: &[usize],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<CoverageReport, FriVeilError> {
+        let codeword_len = 1usize << fri_params.rs_code().log_len();
+        if let Some(&index) = share_indices.iter().find(|&&i| i >= codeword_len) {
+            return Err(FriVeilError::QueryOutOfRange(format!(
+                "Share index {} out of range for codeword of length {}",
+                index, codeword_len
+            )));
+        }
+
+        let held = share_indices.iter().unique().count();
+        let required = 1usize << fri_params.rs_code().log_dim();
+        let recoverable = held >= required;
+        Ok(CoverageReport {
+            held,
+            required,
+            recoverable,
+            recoverable_bytes: if recoverable {
+                0..required * BYTES_PER_ELEMENT
+            } else {
+                0..0
+            },
+        })
+    }
+}
+
+/// Diagnosis produced by [`FriVeilDefault::explain_failure`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FailureExplanation {
+    /// The proof verified
+    Verified,
+    /// The header is malformed or was generated with other parameters
+    Header { detail: String },
+    /// The binding is malformed or binds the proof to an unexpected context
+    Binding { detail: String },
+    /// The point being verified is malformed or not the one the proof is for
+    EvaluationPoint { detail: String },
+    /// The proof ends before the commitment root
+    Truncated { detail: String },
+    /// The embedded commitment root is not the one the verifier expects
+    Commitment { detail: String },
+    /// The proof prefix is sound but the binius PCS verifier rejected the proof
+    Pcs {
+        /// Commitment root the proof was checked against
+        commitment: [u8; 32],
+        /// Bytes of PCS proof data after the prefix
+        pcs_proof_len: usize,
+        /// Error reported by the PCS verifier
+        detail: String,
+    },
+}
+
+impl std::fmt::Display for FailureExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Verified => write!(f, "proof verified"),
+            Self::Header { detail } => write!(f, "proof header rejected: {}", detail),
+            Self::Binding { detail } => write!(f, "proof binding rejected: {}", detail),
+            Self::EvaluationPoint { detail } => write!(f, "evaluation point rejected: {}", detail),
+            Self::Truncated { detail } => write!(f, "proof truncated: {}", detail),
+            Self::Commitment { detail } => write!(f, "commitment rejected: {}", detail),
+            Self::Pcs {
+                commitment,
+                pcs_proof_len,
+                detail,
+            } => write!(
+                f,
+                "PCS verification against commitment {} failed after a valid prefix \
+                 ({} bytes of PCS proof): {}",
+                to_hex(commitment),
+                pcs_proof_len,
+                detail
+            ),
+        }
+    }
+}
+
+/// Result of [`FriVeilDefault::coverage`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// Number of distinct shares held
+    pub held: usize,
+    /// Number of distinct shares needed for reconstruction
+    pub required: usize,
+    /// Whether the held shares meet the reconstruction threshold
+    pub recoverable: bool,
+    /// Byte range of the padded data that can be recovered; empty below the threshold
+    pub recoverable_bytes: Range<usize>,
+}
+
+impl CoverageReport {
+    /// Number of further distinct shares needed before the data is recoverable
+    #[must_use]
+    pub fn missing(&self) -> usize {
+        self.required.saturating_sub(self.held)
+    }
+}
+
+/// Standalone availability proof for a single codeword position
+///
+/// The atomic unit of availability gossip: small enough to send per share and
+/// verifiable with only the commitment root and FRI parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareProof {
+    /// Codeword value at the proven position
+    pub value: B128,
+    /// Serialized Merkle opening for the position
+    pub merkle_path: Vec<u8>,
+}
+
+/// Opening of the header part of a `commit_with_header` commitment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeaderProof {
+    /// Root of the body commitment
+    pub body_root: [u8; 32],
+}
+
+/// Pass/fail breakdown of [`FriVeil::verify_evaluation_many`]
+///
+/// Lets a node score a sampler's whole submission instead of stopping at the first
+/// invalid proof.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Number of proofs that verified
+    pub passed: usize,
+    /// Index into the batch and verification error of every proof that failed
+    pub failed: Vec<(usize, FriVeilError)>,
+}
+
+impl BatchResult {
+    /// Whether every proof in the batch verified
+    #[must_use]
+    pub fn all_passed(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Availability proof for positions sampled across a block of commitments
+///
+/// Produced by [`FriVeil::prove_block_sampling`]. Shares are in sample order; their
+/// positions are rederived from the roots and nonce by the verifier, so none are stored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockSamplingProof {
+    /// One share proof per sample
+    pub shares: Vec<ShareProof>,
+}
+
+/// Derive `(commitment, codeword_index)` for each of `num_samples` block samples
+///
+/// Sample `i` hashes a seed over all roots and the verifier's `nonce` together with `i`
+/// and reduces the first 8 bytes modulo the block's total share count, so samples are
+/// spread uniformly over every position of every commitment (negligible modulo bias)
+/// rather than evenly per commitment. Positions may repeat. Without the nonce they would
+/// be fixed by the roots, and a prover could store only the shares it will be asked for.
+fn block_sample_positions(
+    roots: &[[u8; 32]],
+    nonce: &[u8; 32],
+    codeword_len: usize,
+    num_samples: usize,
+) -> Result<Vec<(usize, usize)>, FriVeilError> {
+    let total_shares = (roots.len() as u64)
+        .checked_mul(codeword_len as u64)
+        .filter(|&total| total > 0)
+        .ok_or_else(|| {
+            FriVeilError::InvalidInput(format!(
+                "Cannot sample a block of {} commitments",
+                roots.len()
+            ))
+        })?;
+
+    let seed: [u8; 32] = roots
+        .iter()
+        .fold(
+            Sha256::new()
+                .chain_update(b"friveil-block-sampling")
+                .chain_update(nonce)
+                .chain_update((roots.len() as u64).to_le_bytes()),
+            |hasher, root| hasher.chain_update(root),
+        )
+        .finalize()
+        .into();
+
+    Ok((0..num_samples as u64)
+        .map(|sample| {
+            let digest = Sha256::new()
+                .chain_update(seed)
+                .chain_update(sample.to_le_bytes())
+                .finalize();
+            let word = u64::from_le_bytes(digest[..8].try_into().expect("digest is 32 bytes"));
+            let position = (word % total_shares) as usize;
+            (position / codeword_len, position % codeword_len)
+        })
+        .collect())
+}
+
+/// Commits many same-sized blobs under one shared FRI context
+///
+/// `initialize_fri_context` builds the NTT domain, which is the expensive part of setup.
+/// A `BatchCommitter` builds it once for a fixed packed buffer length and reuses it for
+/// every `commit`, so looping over many blobs doesn't re-derive parameters per blob.
+/// Each commitment is identical to calling [`FriVeil::commit`] with the same context.
+pub struct BatchCommitter<'f, 'a, P, VCS, NTT>
+where
+    NTT: AdditiveNTT<Field = B128> + Sync,
+    P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
+    VCS: MerkleTreeScheme<P::Scalar>,
+{
+    friveil: &'f FriVeil<'a, P, VCS, NTT>,
+    fri_params: FRIParams<P::Scalar>,
+    ntt: NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+}
+
+impl<'f, 'a, P, VCS, NTT> BatchCommitter<'f, 'a, P, VCS, NTT>
+where
+    NTT: AdditiveNTT<Field = B128> + Sync,
+    P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
+    VCS: MerkleTreeScheme<P::Scalar>,
+{
+    /// Set up a shared FRI context for blobs with the given packed buffer log length
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BatchCommitter)` - Committer holding the FRI parameters and NTT
+    /// * `Err(FriVeilError)` - Error message if the FRI context cannot be initialized
+    pub fn new(
+        friveil: &'f FriVeil<'a, P, VCS, NTT>,
+        packed_buffer_log_len: usize,
+    ) -> Result<Self, FriVeilError> {
+        let (fri_params, ntt) = friveil.initialize_fri_context(packed_buffer_log_len)?;
+        Ok(Self {
+            friveil,
+            fri_params,
+            ntt,
+        })
+    }
+
+    /// FRI parameters shared by every commitment, needed later for proving and verification
+    #[must_use]
+    pub fn fri_params(&self) -> &FRIParams<P::Scalar> {
+        &self.fri_params
+    }
+
+    /// NTT instance shared by every commitment
+    #[must_use]
+    pub fn ntt(&self) -> &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>> {
+        &self.ntt
+    }
+
+    /// Commit to one blob using the shared context
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CommitOutput)` - Same output as [`FriVeil::commit`]
+    /// * `Err(FriVeilError)` - The blob's size doesn't match the shared context, or commit failed
+    pub fn commit(
+        &self,
+        packed_mle: FieldBuffer<P>,
+    ) -> Result<StoredCommitOutput<P>, FriVeilError> {
+        self.friveil
+            .commit(packed_mle, self.fri_params.clone(), &self.ntt)
+    }
+}
+
+impl<P> CommitOutputExt for StoredCommitOutput<P>
+where
+    P: PackedField<Scalar = B128>,
+{
+    /// Approximate heap bytes held by the codeword, commitment and Merkle tree
+    ///
+    /// The tree is estimated as a full binary tree of 32-byte digests over the codeword
+    /// symbols of `fri_params` rather than measured, since its layout is internal to
+    /// binius. It is sized from the parameters, not the codeword vector, so clearing the
+    /// codeword doesn't hide the tree that is still held. Fixed-size struct overhead is
+    /// ignored, so the result scales linearly with the codeword length.
+    fn memory_footprint(&self, fri_params: &FRIParams<B128>) -> usize {
+        const DIGEST_BYTES: usize = 32;
+
+        let codeword_bytes = self.codeword.len() * std::mem::size_of::<P>();
+        let num_leaves = 1usize << (fri_params.rs_code().log_len() + fri_params.log_batch_size());
+        let tree_bytes = (2 * num_leaves).saturating_sub(1) * DIGEST_BYTES;
+
+        codeword_bytes + self.commitment.len() + tree_bytes
+    }
+
+    /// Commitment root as a fixed-width array, see [`Commitment`]
+    ///
+    /// `commitment` is a public field, so an output built or edited by hand may hold a
+    /// root of the wrong width; that is an error rather than a panic.
+    fn root(&self) -> Result<[u8; 32], FriVeilError> {
+        Commitment::from_bytes(&self.commitment).map(Into::into)
+    }
+
+    /// Content identifier of the commitment root, see [`commitment_cid`]
+    fn cid(&self) -> Vec<u8> {
+        commitment_cid(&self.commitment)
+    }
+
+    /// Root to publish for this commitment under `nonce`, see [`nonced_root`]
+    fn nonced_root(&self, nonce: &[u8; 32]) -> [u8; 32] {
+        nonced_root(&self.commitment, nonce)
+    }
+
+    /// Drop the codeword and Merkle tree once every needed proof has been generated
+    ///
+    /// Consumes the output and returns only its root, which is what identity and
+    /// comparison need. The Merkle tree is an opaque binius value that cannot be emptied
+    /// in place, so the whole output is dropped rather than cleared, and nothing is left
+    /// to prove against. Fails like [`Self::root`] if the root is not 32 bytes.
+    fn prune(self) -> Result<Commitment, FriVeilError> {
+        Commitment::from_bytes(&self.commitment)
+    }
+}
+
+impl<'a, P, VCS, NTT> FriVeilSampling<P, NTT> for FriVeil<'a, P, VCS, NTT>
+where
+    NTT: AdditiveNTT<Field = B128> + Sync,
+    P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
+    VCS: MerkleTreeScheme<P::Scalar>,
+{
+    /// Decode a Reed-Solomon codeword with error correction for missing points
+    /// This implements proper Reed-Solomon erasure decoding using polynomial interpolation
+    /// Note: Extremely naive algorithm, primarily for soundness guarantee demonstration.
+    /// # Performance
+    /// - When compiled with `--features parallel`, uses rayon for parallel processing
+    /// - When compiled without the parallel feature, uses sequential processing
+    fn reconstruct_codeword_naive(
+        &self,
+        corrupted_codeword: &mut [P::Scalar],
+        corrupted_indices: &[usize],
+    ) -> Result<(), FriVeilError> {
+        let n = corrupted_codeword.len();
+        let domain = (0..corrupted_codeword.len())
+            .map(|i| P::Scalar::from(i as u128))
+            .collect::<Vec<_>>();
+        if corrupted_indices.is_empty() {
+            return Ok(());
+        }
+        if let Some(&index) = corrupted_indices.iter().find(|&&i| i >= n) {
+            return Err(FriVeilError::QueryOutOfRange(format!(
+                "Corrupted index {} out of range for codeword of length {}",
+                index, n
+            )));
+        }
+
+        // Collect known points (x_j, y_j)
+        let known: Vec<(P::Scalar, P::Scalar)> = (0..n)
+            .filter(|i| !corrupted_indices.contains(i))
+            .map(|i| (domain[i], corrupted_codeword[i]))
+            .collect();
+
+        let k = known.len();
+        if k == 0 {
+            return Err(FriVeilError::InvalidInput(
+                "No known points available for reconstruction".to_string(),
+            ));
+        }
+
+        // For each erased position, interpolate and evaluate
+        #[cfg(feature = "parallel")]
+        {
+            // Parallel version using rayon
+            let reconstructed_values: Vec<(usize, P::Scalar)> = corrupted_indices
+                .par_iter()
+                .map(|&missing| {
+                    debug!("Calculating value for missing index: {}", missing);
+                    let x_e = domain[missing];
+
+                    let mut value = P::Scalar::zero();
+
+                    for j in 0..k {
+                        let (x_j, y_j) = known[j];
+
+                        // Compute L_j(x_e)
+                        let mut l_j = P::Scalar::ONE;
+                        for m in 0..k {
+                            if m == j {
+                                continue;
+                            }
+                            let (x_m, _) = known[m];
+                            l_j = l_j
+                                * (x_e - x_m)
+                                * (x_j - x_m).invert().expect("domain points are distinct");
+                        }
+
+                        value = value + y_j * l_j;
+                    }
+
+                    debug!(
+                        "Reconstructed value for missing index {}: {:?}",
+                        missing, value
+                    );
+                    (missing, value)
+                })
+                .collect();
+
+            // Apply the reconstructed values to the codeword
+            for (missing, value) in reconstructed_values {
+                corrupted_codeword[missing] = value;
+            }
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            // Sequential version
+            for &missing in corrupted_indices {
+                debug!("Calculating value for missing index: {}", missing);
+                let x_e = domain[missing];
+
+                let mut value = P::Scalar::zero();
+
+                for j in 0..k {
+                    let (x_j, y_j) = known[j];
+
+                    // Compute L_j(x_e)
+                    let mut l_j = P::Scalar::ONE;
+                    for m in 0..k {
+                        if m == j {
+                            continue;
+                        }
+                        let (x_m, _) = known[m];
+                        l_j = l_j
+                            * (x_e - x_m)
+                            * (x_j - x_m).invert().expect("domain points are distinct");
+                    }
+
+                    value = value + y_j * l_j;
+                }
+
+                debug!(
+                    "Reconstructed value for missing index {}: {:?}",
+                    missing, value
+                );
+                corrupted_codeword[missing] = value;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify an evaluation proof for the committed polynomial
+    ///
+    /// Verifies that a polynomial evaluates to a claimed value at a given point
+    /// using the FRI-based proof in the transcript.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the proof
+    /// * `evaluation_claim` - Claimed evaluation result
+    /// * `evaluation_point` - Point at which polynomial was evaluated
+    /// * `fri_params` - FRI protocol parameters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid
+    /// * `Err(FriVeilError)` - Proof is invalid or verification failed
+    ///
+    /// # Process
+    ///
+    /// 1. Check the proof header against `fri_params`
+    /// 2. Extract commitment from transcript
+    /// 3. Run FRI verification protocol
+    /// 4. Check consistency with claimed evaluation
+    fn verify_evaluation(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Any,
+            &ProofBinding::new(),
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Verify an evaluation proof against a known commitment root
+    ///
+    /// Same as `verify_evaluation`, but additionally rejects the proof if the
+    /// commitment embedded in the transcript differs from `commitment`, before any
+    /// FRI work is done.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the proof
+    /// * `commitment` - Expected Merkle root (32 bytes)
+    /// * `evaluation_claim` - Claimed evaluation result
+    /// * `evaluation_point` - Point at which polynomial was evaluated
+    /// * `fri_params` - FRI protocol parameters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid for the given root
+    /// * `Err(FriVeilError)` - Commitment mismatch, or the proof is invalid
+    fn verify_evaluation_with_root(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        commitment: [u8; 32],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Root(commitment),
+            &ProofBinding::new(),
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Verify an evaluation proof against a hex-encoded commitment root
+    ///
+    /// Convenience wrapper over `verify_evaluation_with_root` for roots taken from
+    /// block explorers or contracts. See [`parse_commitment_hex`] for the accepted format.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid for the given root
+    /// * `Err(FriVeilError)` - Malformed hex, commitment mismatch, or invalid proof
+    fn verify_evaluation_with_root_hex(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        root_hex: &str,
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        let commitment = parse_commitment_hex(root_hex)?;
+        self.verify_evaluation_with_root(
+            verifier_transcript,
+            commitment,
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Verify an evaluation proof against a content identifier of its commitment
+    ///
+    /// Recomputes [`commitment_cid`] from the root embedded in the transcript and
+    /// rejects the proof if it differs from `cid`, before any FRI work is done. This
+    /// binds verification to the CID a content-addressed storage layer serves the blob
+    /// under.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the proof
+    /// * `cid` - Expected CID, as returned by `CommitOutputExt::cid`
+    /// * `evaluation_claim` - Claimed evaluation result
+    /// * `evaluation_point` - Point at which polynomial was evaluated
+    /// * `fri_params` - FRI protocol parameters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid for the commitment named by `cid`
+    /// * `Err(FriVeilError)` - CID mismatch, or the proof is invalid
+    fn verify_evaluation_with_cid(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        cid: &[u8],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Cid(cid),
+            &ProofBinding::new(),
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Verify an evaluation proof against a nonced commitment root
+    ///
+    /// The proof must have been generated with `prove_bound` under
+    /// `ProofBinding::new().with_nonce(*nonce)`. The root embedded in the transcript is
+    /// hashed with `nonce` via [`nonced_root`] and compared against `nonced_root`
+    /// before any FRI work is done.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the proof
+    /// * `nonced_root` - Published root, as returned by `CommitOutputExt::nonced_root`
+    /// * `nonce` - Nonce the root was randomized with
+    /// * `evaluation_claim` - Claimed evaluation result
+    /// * `evaluation_point` - Point at which polynomial was evaluated
+    /// * `fri_params` - FRI protocol parameters
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Proof is valid for the commitment published as `nonced_root`
+    /// * `Err(FriVeilError)` - Nonce or root mismatch, or the proof is invalid
+    fn verify_evaluation_with_nonce(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        nonced_root: [u8; 32],
+        nonce: &[u8; 32],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError> {
+        self.verify_evaluation_against(
+            verifier_transcript,
+            ExpectedCommitment::Nonced {
+                root: nonced_root,
+                nonce,
+            },
+            &ProofBinding::new().with_nonce(*nonce),
+            evaluation_claim,
+            evaluation_point,
+            fri_params,
+        )
+    }
+
+    /// Generate a Merkle inclusion proof for a specific codeword position
+    ///
+    /// Creates a proof that a value at a given index is part of the committed
+    /// codeword. This is used for data availability sampling.
+    ///
+    /// # Arguments
+    ///
+    /// * `committed` - Merkle tree commitment structure
+    /// * `index` - Position in the codeword to prove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Transcript containing the inclusion proof
+    /// * `Err(FriVeilError)` - Error generating the proof
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let proof = friveil.inclusion_proof(&commit_output.committed, 42)?;
+    /// ```
+    fn inclusion_proof(
+        &self,
+        committed: &<BinaryMerkleTreeProver<
+            P::Scalar,
+            StdDigest,
+            ParallelCompressionAdaptor<StdCompression>,
+        > as MerkleTreeProver<P::Scalar>>::Committed,
+        index: usize,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        let mut proof_writer = ProverTranscript::new(StdChallenger::default());
+        self.merkle_prover
+            .prove_opening(committed, 0, index, &mut proof_writer.message())
+            .map_err(FriVeilError::binius)?;
+
+        let proof_reader = proof_writer.into_verifier();
+
+        Ok(proof_reader)
+    }
+
+    /// Verify a Merkle inclusion proof for a codeword value
+    ///
+    /// Verifies that a value at a specific index is correctly committed
+    /// in the Merkle tree. This is the verification counterpart to `inclusion_proof`.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the inclusion proof
+    /// * `data` - Value(s) to verify
+    /// * `index` - Position in the codeword
+    /// * `fri_params` - FRI parameters (for context)
+    /// * `commitment` - Merkle root commitment (32 bytes)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Inclusion proof is valid
+    /// * `Err(FriVeilError)` - Proof is invalid or verification failed
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// friveil.verify_inclusion_proof(
+    ///     &mut proof,
+    ///     &[value],
+    ///     index,
+    ///     &fri_params,
+    ///     commitment_bytes,
+    /// )?;
+    /// ```
+    fn verify_inclusion_proof(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        data: &[P::Scalar],
+        index: usize,
+        fri_params: &FRIParams<P::Scalar>,
+        commitment: [u8; 32],
+    ) -> Result<(), FriVeilError> {
+        let tree_depth = fri_params.rs_code().log_len();
+        if index >= 1 << tree_depth {
+            return Err(FriVeilError::QueryOutOfRange(format!(
+                "Index {} out of range for codeword of length {}",
+                index,
+                1usize << tree_depth
+            )));
+        }
+        self.merkle_prover
+            .scheme()
+            .verify_opening(
+                index,
+                data,
+                0,
+                tree_depth,
+                &[commitment.into()],
+                &mut verifier_transcript.message(),
+            )
+            .map_err(FriVeilError::binius)
+    }
+
+    /// Decode a Reed-Solomon encoded codeword back to original data
+    ///
+    /// Applies inverse Reed-Solomon transformation to recover the original
+    /// data from the encoded codeword. This is the inverse of `encode_codeword`.
+    ///
+    /// # Arguments
+    ///
+    /// * `codeword` - Reed-Solomon encoded codeword
+    /// * `fri_params` - FRI parameters containing RS code configuration
+    /// * `ntt` - NTT instance for efficient decoding
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<P::Scalar>)` - Decoded original data
+    /// * `Err(FriVeilError)` - Error message if decoding fails
+    ///
+    /// # Process
+    ///
+    /// 1. Calculate expected output length
+    /// 2. Apply inverse NTT transformation
+    /// 3. Trim to original data size (remove redundancy)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let decoded = friveil.decode_codeword(&encoded, fri_params, &ntt)?;
+    /// assert_eq!(decoded, original_data);
+    /// ```
+    fn decode_codeword(
+        &self,
+        codeword: &[P::Scalar],
+        fri_params: FRIParams<P::Scalar>,
+        ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
+    ) -> Result<Vec<P::Scalar>, FriVeilError> {
+        let rs_code = fri_params.rs_code();
+        let len = 1 << (rs_code.log_len() + fri_params.log_batch_size() - P::LOG_WIDTH);
+
+        let mut decoded = Vec::with_capacity(len);
+        self.decode_batch(
+            rs_code.log_len(),
+            rs_code.log_inv_rate(),
+            fri_params.log_batch_size(),
+            ntt,
+            codeword.as_ref(),
+            decoded.spare_capacity_mut(),
+        )
+        .map_err(FriVeilError::binius)?;
+
+        unsafe {
+            // Safety: decode_batch guarantees all elements are initialized on success
+            decoded.set_len(len);
+        }
+
+        // Trim to original data size (remove redundancy)
+        let trim_len = 1 << (rs_code.log_dim() + fri_params.log_batch_size() - P::LOG_WIDTH);
+        decoded.resize(trim_len, P::Scalar::zero());
+        Ok(decoded)
+    }
+
+    /// Extract commitment from verifier transcript
+    ///
+    /// Helper function to read the commitment bytes from a transcript.
+    /// This is used internally for verification workflows.
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier_transcript` - Transcript containing the commitment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - Commitment bytes
+    /// * `Err(FriVeilError)` - Error reading from transcript
+    ///
+    /// # Note
+    ///
+    /// Marked as `#[allow(dead_code)]` as it's currently unused but
+    /// may be useful for future transcript manipulation.
+    #[allow(dead_code)]
+    fn extract_commitment(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+    ) -> Result<Vec<u8>, FriVeilError> {
+        verifier_transcript
+            .message()
+            .read()
+            .map_err(FriVeilError::binius)
+    }
+
+    /// Low-level batch decoding using inverse NTT
+    ///
+    /// Performs the actual Reed-Solomon decoding operation using inverse NTT.
+    /// This is called by `decode_codeword` and handles the core transformation.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_len` - Logarithm of codeword length
+    /// * `log_inv` - Logarithm of inverse rate (redundancy factor)
+    /// * `log_batch_size` - Logarithm of batch size
+    /// * `ntt` - NTT instance for transformation
+    /// * `data` - Input codeword data
+    /// * `output` - Uninitialized output buffer
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Decoding successful, output buffer is initialized
+    /// * `Err(FriVeilError)` - Decoding failed
+    ///
+    /// # Safety
+    ///
+    /// On success, guarantees that all elements in `output` are properly initialized.
+    ///
+    /// # Implementation Details
+    ///
+    /// 1. Validates input dimensions
+    /// 2. Copies data to output buffer
+    /// 3. Applies inverse NTT with appropriate skip parameters
+    /// 4. Handles both packed and unpacked field representations
+    fn decode_batch(
+        &self,
+        log_len: usize,
         log_inv: usize,
         log_batch_size: usize,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
         data: &[P::Scalar],
         output: &mut [MaybeUninit<P::Scalar>],
-    ) -> Result<(), String> {
+    ) -> Result<(), FriVeilError> {
         let data_log_len = log_len + log_batch_size;
 
-        let expected_data_len = if data_log_len >= P::LOG_WIDTH {
-            1 << (data_log_len - P::LOG_WIDTH)
-        } else {
-            1
-        };
+        let expected_data_len = if data_log_len >= P::LOG_WIDTH {
+            1 << (data_log_len - P::LOG_WIDTH)
+        } else {
+            1
+        };
+
+        if data.len() != expected_data_len {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Unexpected data length: {} {} ",
+                expected_data_len,
+                data.len()
+            )));
+        }
+
+        let _scope = tracing::trace_span!(
+            "Reed-Solomon encode",
+            log_len = log_len,
+            log_batch_size = log_batch_size,
+        )
+        .entered();
+
+        let data_portion_len = data.len();
+        if output.len() < data_portion_len {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Output buffer too small: {} < {}",
+                output.len(),
+                data_portion_len
+            )));
+        }
+
+        for i in 0..data_portion_len {
+            output[i].write(data[i]);
+        }
+
+        for i in data_portion_len..output.len() {
+            output[i].write(P::Scalar::zero());
+        }
+
+        let output_initialized =
+            unsafe { uninit::out_ref::Out::<[P::Scalar]>::from(output).assume_init() };
+        let mut code = FieldSliceMut::from_slice(log_len + log_batch_size, output_initialized)
+            .map_err(FriVeilError::binius)?;
+
+        let skip_early = log_inv;
+        let skip_late = log_batch_size;
+
+        // TODO: create an optimised version PR to binius 64 for inverse_ntt
+        let log_d = code.log_len();
+        use binius_math::ntt::DomainContext;
+        for layer in (skip_early..(log_d - skip_late)).rev() {
+            let num_blocks = 1 << layer;
+            let block_size_half = 1 << (log_d - layer - 1);
+            for block in 0..num_blocks {
+                let twiddle = ntt.domain_context().twiddle(layer, block);
+                let block_start = block << (log_d - layer);
+                for idx0 in block_start..(block_start + block_size_half) {
+                    let idx1 = block_size_half | idx0;
+                    // perform butterfly
+                    let mut u = code.get(idx0);
+                    let mut v = code.get(idx1);
+
+                    v += u;
+                    u += v * twiddle;
+                    code.set(idx0, u);
+                    code.set(idx1, v);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Fixed-width commitment root
+///
+/// Roots are [`StdDigest`] (SHA-256) Merkle roots, so always [`Self::LEN`] bytes; use
+/// this instead of `CommitOutput::commitment`'s `Vec<u8>` where the width matters, e.g.
+/// as the key of an on-chain mapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Commitment([u8; 32]);
+
+impl Commitment {
+    /// Byte width of a commitment root
+    pub const LEN: usize = 32;
+
+    /// Wrap a root of exactly [`Self::LEN`] bytes
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Commitment)` - The root
+    /// * `Err(FriVeilError)` - `bytes` is not [`Self::LEN`] bytes long
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FriVeilError> {
+        let root = bytes.try_into().map_err(|_| {
+            FriVeilError::InvalidInput(format!(
+                "Commitment must be {} bytes, got {}",
+                Self::LEN,
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(root))
+    }
+
+    /// The root bytes
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Commitment {
+    fn from(root: [u8; 32]) -> Self {
+        Self(root)
+    }
+}
+
+impl From<Commitment> for [u8; 32] {
+    fn from(commitment: Commitment) -> Self {
+        commitment.0
+    }
+}
+
+/// Parse a hex-encoded 32-byte commitment root
+///
+/// Accepts exactly 64 hex digits, upper or lower case, with an optional `0x` prefix.
+///
+/// # Returns
+///
+/// * `Ok([u8; 32])` - Decoded root
+/// * `Err(FriVeilError)` - Wrong length or a non-hex character
+pub fn parse_commitment_hex(root_hex: &str) -> Result<[u8; 32], FriVeilError> {
+    let digits = root_hex.strip_prefix("0x").unwrap_or(root_hex);
+    if digits.len() != 64 {
+        return Err(FriVeilError::FieldDecode(format!(
+            "Invalid commitment hex: expected 64 hex digits, got {}",
+            digits.len()
+        )));
+    }
+
+    let mut root = [0u8; 32];
+    for (byte, pair) in root.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair)
+            .map_err(|e| FriVeilError::FieldDecode(format!("Invalid commitment hex: {}", e)))?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| {
+            FriVeilError::FieldDecode(format!("Invalid commitment hex: non-hex digits {:?}", pair))
+        })?;
+    }
+    Ok(root)
+}
+
+/// Content identifier of a commitment root
+///
+/// Encoded as a binary CIDv1 with the `raw` codec over a SHA-256 multihash of the root,
+/// matching what IPFS-like layers use to address the commitment:
+///
+/// ```text
+/// | 0x01 (CIDv1) | 0x55 (raw) | 0x12 (sha2-256) | 0x20 (32 bytes) | sha256(root) |
+/// ```
+#[must_use]
+pub fn commitment_cid(root: &[u8]) -> Vec<u8> {
+    let digest: [u8; 32] = Sha256::digest(root).into();
+    [&[0x01, 0x55, 0x12, 0x20][..], &digest].concat()
+}
+
+/// SHA-256 digest of a blob header committed with `commit_with_header`
+#[must_use]
+pub fn header_digest(header: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(b"friveil-header")
+        .chain_update(header)
+        .finalize()
+        .into()
+}
+
+/// Combined root of a header digest and a body commitment root
+#[must_use]
+pub fn header_body_root(header_digest: &[u8; 32], body_root: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(b"friveil-header-body")
+        .chain_update(header_digest)
+        .chain_update(body_root)
+        .finalize()
+        .into()
+}
+
+/// Commitment root randomized with a caller-chosen nonce
+///
+/// Two commitments to identical data share a Merkle root, so publishing roots reveals
+/// when blobs are equal. Publishing `sha256("friveil-nonced-root" || nonce || root)`
+/// instead gives a distinct but reproducible value per nonce. Only the published value
+/// is randomized: proofs still embed the plain root and open the same codeword, so
+/// anyone holding two proofs can still link the commitments. Prove with
+/// `ProofBinding::with_nonce` and verify with `verify_evaluation_with_nonce`.
+#[must_use]
+pub fn nonced_root(root: &[u8], nonce: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(b"friveil-nonced-root")
+        .chain_update(nonce)
+        .chain_update(root)
+        .finalize()
+        .into()
+}
+
+/// SHA-256 digest of an evaluation point, as written into every proof
+///
+/// `prove` absorbs it ahead of the commitment root, and verification recomputes it from
+/// the point it is given. Passing the wrong point is then reported as such before any FRI
+/// work, instead of failing (or, for a coincidentally valid claim, passing) deep inside
+/// the PCS verifier.
+#[must_use]
+pub fn point_digest(evaluation_point: &[B128]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(b"friveil-evaluation-point")
+        .chain_update(encode_point(evaluation_point))
+        .finalize()
+        .into()
+}
+
+/// Decode an evaluation point from 16 little-endian bytes per coordinate
+///
+/// Inverse of the encoding [`point_digest`] hashes. Every 16-byte string is the canonical
+/// encoding of exactly one B128 element: the field is GF(2^128) given by its 128
+/// coefficient bits, so unlike a prime field there is no out-of-range value that
+/// `u128::from_le_bytes` could wrap. Only the framing can be malformed, and that is
+/// rejected here rather than silently dropping a partial coordinate.
+///
+/// # Returns
+///
+/// * `Ok(Vec<B128>)` - One coordinate per 16 bytes
+/// * `Err(FriVeilError)` - `bytes` is not a whole number of coordinates
+pub fn decode_evaluation_point(bytes: &[u8]) -> Result<Vec<B128>, FriVeilError> {
+    if bytes.len() % BYTES_PER_ELEMENT != 0 {
+        return Err(FriVeilError::FieldDecode(format!(
+            "Evaluation point of {} bytes is not a whole number of {}-byte coordinates",
+            bytes.len(),
+            BYTES_PER_ELEMENT
+        )));
+    }
+    Ok(bytes
+        .chunks_exact(BYTES_PER_ELEMENT)
+        .map(|chunk| {
+            B128::from(u128::from_le_bytes(
+                chunk.try_into().expect("chunk is one element"),
+            ))
+        })
+        .collect())
+}
+
+/// Commitment a proof is expected to carry, checked before any FRI work
+///
+/// Each `verify_evaluation*` method checks one of these; pass the same one to
+/// [`FriVeilDefault::explain_failure`] to diagnose a proof it rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpectedCommitment<'a> {
+    /// Accept whatever root the proof embeds
+    Any,
+    /// The embedded root must equal this root
+    Root([u8; 32]),
+    /// The embedded root's [`commitment_cid`] must equal this CID
+    Cid(&'a [u8]),
+    /// The embedded root's [`nonced_root`] under `nonce` must equal `root`
+    Nonced {
+        /// Published nonced root
+        root: [u8; 32],
+        /// Nonce the root was derived with
+        nonce: &'a [u8; 32],
+    },
+    /// The embedded body root's [`header_body_root`] with `header_digest` must equal `root`
+    WithHeader {
+        /// Published combined root
+        root: [u8; 32],
+        /// [`header_digest`] of the blob header
+        header_digest: &'a [u8; 32],
+    },
+}
+
+impl ExpectedCommitment<'_> {
+    fn check(&self, root: &[u8; 32]) -> Result<(), FriVeilError> {
+        match self {
+            Self::Any => {}
+            Self::Root(expected_root) => {
+                if !ct_eq(root, expected_root) {
+                    return Err(FriVeilError::CommitmentMismatch(format!(
+                        "Commitment mismatch: proof commits to {} but expected {}",
+                        to_hex(root),
+                        to_hex(expected_root)
+                    )));
+                }
+            }
+            Self::Cid(expected_cid) => {
+                let cid = commitment_cid(root);
+                if !ct_eq(&cid, expected_cid) {
+                    return Err(FriVeilError::CommitmentMismatch(format!(
+                        "CID mismatch: proof commitment has CID {} but expected {}",
+                        to_hex(&cid),
+                        to_hex(expected_cid)
+                    )));
+                }
+            }
+            Self::Nonced {
+                root: expected_root,
+                nonce,
+            } => {
+                let nonced = nonced_root(root, nonce);
+                if !ct_eq(&nonced, expected_root) {
+                    return Err(FriVeilError::CommitmentMismatch(format!(
+                        "Commitment mismatch: proof commits to nonced root {} but expected {}",
+                        to_hex(&nonced),
+                        to_hex(expected_root)
+                    )));
+                }
+            }
+            Self::WithHeader {
+                root: expected_root,
+                header_digest,
+            } => {
+                let combined = header_body_root(header_digest, root);
+                if !ct_eq(&combined, expected_root) {
+                    return Err(FriVeilError::CommitmentMismatch(format!(
+                        "Commitment mismatch: proof body with header gives {} but expected {}",
+                        to_hex(&combined),
+                        to_hex(expected_root)
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Constant-time byte comparison for equal-length inputs
+///
+/// Accumulates the XOR of every byte pair instead of returning at the first difference;
+/// `black_box` keeps the compiler from reintroducing an early exit.
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |acc, (x, y)| std::hint::black_box(acc | (x ^ y)));
+    diff == 0
+}
+
+/// Lowercase hex encoding used in error messages
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut out, byte| {
+            let _ = write!(out, "{:02x}", byte);
+            out
+        })
+}
+
+/// Log2 of the number of elements in a record slot of `record_size` bytes
+fn log_record_elements(record_size: usize) -> Result<usize, FriVeilError> {
+    if record_size == 0 {
+        return Err(FriVeilError::InvalidInput(
+            "Records must not be empty".to_string(),
+        ));
+    }
+    Ok(Utils::<B128>::record_slot_elements(record_size).ilog2() as usize)
+}
+
+/// Stage of the proof prefix at which [`read_proof_prefix`] stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PrefixStage {
+    /// The header is malformed or doesn't match `fri_params`
+    Header,
+    /// The binding is malformed or doesn't match the expected binding
+    Binding,
+    /// The point has the wrong length or isn't the one the proof is for
+    EvaluationPoint,
+    /// The proof ends before the point digest or commitment root
+    Truncated,
+    /// The embedded root isn't the expected commitment
+    Commitment,
+}
+
+/// Read the proof prefix stage by stage and check it against what the verifier expects
+///
+/// Checks the point length against `fri_params`, the [`ProofHeader`] against
+/// `fri_params`, the [`ProofBinding`] against `expected_binding`, the proof's
+/// [`point_digest`] against `evaluation_point` and the embedded commitment root against
+/// `expected_commitment`, in transcript order. Both verification and
+/// [`FriVeilDefault::explain_failure`] go through here, so they read the same prefix.
+///
+/// # Returns
+///
+/// * `Ok([u8; 32])` - Embedded commitment root; the transcript is left at the PCS proof
+/// * `Err((PrefixStage, FriVeilError))` - First failing check and its stage
+fn read_proof_prefix(
+    verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+    expected_commitment: &ExpectedCommitment<'_>,
+    expected_binding: &ProofBinding,
+    evaluation_point: &[B128],
+    fri_params: &FRIParams<B128>,
+) -> Result<[u8; 32], (PrefixStage, FriVeilError)> {
+    let at = |stage: PrefixStage| move |error: FriVeilError| (stage, error);
+
+    check_evaluation_point_len(evaluation_point.len(), fri_params)
+        .map_err(at(PrefixStage::EvaluationPoint))?;
+
+    // Check the proof was generated with the same parameters
+    let mut header_bytes = [0u8; ProofHeader::ENCODED_LEN];
+    read_transcript_bytes(verifier_transcript, &mut header_bytes)
+        .and_then(|()| ProofHeader::from_bytes(&header_bytes))
+        .and_then(|header| header.check_against(fri_params))
+        .map_err(at(PrefixStage::Header))?;
+
+    // Check the proof is bound to the context the verifier expects
+    read_binding(verifier_transcript)
+        .and_then(|binding| binding.check_against(expected_binding))
+        .map_err(at(PrefixStage::Binding))?;
+
+    // Check the proof was generated for the point being verified
+    let mut retrieved_point_digest = [0u8; 32];
+    read_transcript_bytes(verifier_transcript, &mut retrieved_point_digest)
+        .map_err(at(PrefixStage::Truncated))?;
+    if retrieved_point_digest != point_digest(evaluation_point) {
+        return Err((PrefixStage::EvaluationPoint, FriVeilError::PointMismatch));
+    }
+
+    // Extract commitment from transcript
+    let mut retrieved_codeword_commitment = [0u8; 32];
+    read_transcript_bytes(verifier_transcript, &mut retrieved_codeword_commitment)
+        .map_err(at(PrefixStage::Truncated))?;
+    expected_commitment
+        .check(&retrieved_codeword_commitment)
+        .map_err(at(PrefixStage::Commitment))?;
+
+    Ok(retrieved_codeword_commitment)
+}
+
+/// Read the length-prefixed [`ProofBinding`] that follows the proof header
+fn read_binding(
+    verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+) -> Result<ProofBinding, FriVeilError> {
+    let mut binding_len = [0u8; 2];
+    read_transcript_bytes(verifier_transcript, &mut binding_len)?;
+    let binding_len = u16::from_le_bytes(binding_len) as usize;
+    if binding_len > MAX_BINDING_LEN {
+        return Err(FriVeilError::InvalidInput(format!(
+            "Proof binding of {} bytes exceeds the maximum of {}",
+            binding_len, MAX_BINDING_LEN
+        )));
+    }
+    let mut binding_bytes = vec![0u8; binding_len];
+    read_transcript_bytes(verifier_transcript, &mut binding_bytes)?;
+    ProofBinding::from_bytes(&binding_bytes)
+}
+
+/// Parameters and context carried by a serialized proof
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofMetadata {
+    /// Parameters the proof was generated with
+    pub header: ProofHeader,
+    /// Context the proof is bound to
+    pub binding: ProofBinding,
+    /// Byte length of the attested data, if the proof was bound to one
+    pub data_len: Option<u64>,
+    /// Commitment root the proof is for
+    pub commitment: [u8; 32],
+}
+
+/// Read a proof's header, binding and commitment root without verifying it
+///
+/// Nothing here is authenticated until the proof is verified; use it to route or
+/// pre-filter proofs (e.g. by expected blob size), and pass the same values as
+/// expectations to a `verify_evaluation*` call that actually checks them.
+///
+/// # Returns
+///
+/// * `Ok(ProofMetadata)` - Decoded metadata
+/// * `Err(FriVeilError)` - Truncated or malformed proof prefix
+pub fn proof_metadata(proof_bytes: &[u8]) -> Result<ProofMetadata, FriVeilError> {
+    let mut verifier_transcript =
+        VerifierTranscript::new(StdChallenger::default(), proof_bytes.to_vec());
+
+    let mut header_bytes = [0u8; ProofHeader::ENCODED_LEN];
+    read_transcript_bytes(&mut verifier_transcript, &mut header_bytes)?;
+    let header = ProofHeader::from_bytes(&header_bytes)?;
+    let binding = read_binding(&mut verifier_transcript)?;
+    let mut point_digest = [0u8; 32];
+    read_transcript_bytes(&mut verifier_transcript, &mut point_digest)?;
+    let mut commitment = [0u8; 32];
+    read_transcript_bytes(&mut verifier_transcript, &mut commitment)?;
+
+    Ok(ProofMetadata {
+        header,
+        data_len: binding.data_len,
+        binding,
+        commitment,
+    })
+}
+
+/// Check a serialized proof is well formed for `fri_params` without its commitment
+///
+/// Meant for relays that forward proofs without knowing the commitment root, claim or
+/// point. Checks that the header parses and matches `fri_params`, so the proof answers
+/// the expected number of queries at the expected rate, that the binding decodes and
+/// the point digest and root are present, and that the proof length lies between
+/// [`FriVeil::min_proof_bytes`] and [`FriVeil::max_proof_bytes`] for `fri_params`. A
+/// valid prefix followed by a few garbage bytes, or by padding, is therefore rejected.
+///
+/// # Note
+///
+/// This does not check FRI consistency. The binius PCS verifier runs the sumcheck over
+/// the evaluation claim and point interleaved with FRI folding, and the fold challenges
+/// depend on both, so no part of the FRI check can run without them. A passing proof
+/// can still fail full verification, e.g. for the wrong commitment.
+///
+/// # Returns
+///
+/// * `Ok(())` - The proof is structurally valid
+/// * `Err(FriVeilError)` - Malformed, too short or too long proof, or parameter mismatch
+pub fn verify_proof_structure(
+    proof_bytes: &[u8],
+    fri_params: &FRIParams<B128>,
+) -> Result<(), FriVeilError> {
+    let metadata = proof_metadata(proof_bytes)?;
+    metadata.header.check_against(fri_params)?;
+
+    check_proof_len(proof_bytes.len(), FriVeilDefault::max_proof_len(fri_params))?;
+    let min_len = FriVeilDefault::min_proof_len(fri_params) + metadata.binding.to_bytes().len();
+    if proof_bytes.len() < min_len {
+        return Err(FriVeilError::InvalidInput(format!(
+            "Proof too short: {} bytes is below the minimum of {} for these parameters",
+            proof_bytes.len(),
+            min_len
+        )));
+    }
+    Ok(())
+}
+
+/// Read exactly `out.len()` bytes from the transcript's Fiat-Shamir message stream
+fn read_transcript_bytes(
+    verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+    out: &mut [u8],
+) -> Result<(), FriVeilError> {
+    let mut message_reader = verifier_transcript.message();
+    let buffer = message_reader.buffer();
+    if buffer.remaining() < out.len() {
+        return Err(FriVeilError::TranscriptTruncated(format!(
+            "Transcript truncated: needed {} bytes, {} remaining",
+            out.len(),
+            buffer.remaining()
+        )));
+    }
+    buffer.copy_to_slice(out);
+    Ok(())
+}
+
+/// Expand `seed` into `len` field elements
+///
+/// Coordinate `i` is the first 16 bytes, little-endian, of SHA-256 over a domain tag,
+/// `seed` and `i` as a `u64`. Derived points are part of the proof format, so this is
+/// pinned here rather than left to an RNG such as `StdRng`, whose algorithm may change
+/// between `rand` releases.
+fn expand_point(seed: &[u8; 32], len: usize) -> Vec<B128> {
+    (0..len as u64)
+        .map(|i| {
+            let digest = Sha256::new()
+                .chain_update(b"friveil-point-expansion")
+                .chain_update(seed)
+                .chain_update(i.to_le_bytes())
+                .finalize();
+            B128::from(u128::from_le_bytes(
+                digest[..16].try_into().expect("digest is 32 bytes"),
+            ))
+        })
+        .collect()
+}
+
+/// Reject commit outputs whose codeword has been cleared
+///
+/// [`CommitOutputExt::prune`] consumes the output, so a pruned commitment can't reach
+/// `prove` at all; this catches outputs whose public `codeword` was emptied by hand.
+fn check_not_pruned<P, C>(commit_output: &CommitOutput<P, Vec<u8>, C>) -> Result<(), FriVeilError> {
+    if commit_output.codeword.is_empty() {
+        return Err(FriVeilError::CodewordPruned);
+    }
+    Ok(())
+}
+
+/// Check that a packed MLE of length `2^packed_log_len` is the message size of `fri_params`
+///
+/// The PCS prover asserts on this internally; checking here turns it into an error.
+fn check_packed_mle_fits(
+    packed_log_len: usize,
+    fri_params: &FRIParams<B128>,
+) -> Result<(), FriVeilError> {
+    let expected_log_len = fri_params.rs_code().log_dim() + fri_params.log_batch_size();
+    if packed_log_len != expected_log_len {
+        return Err(FriVeilError::InvalidLogLen(format!(
+            "Packed MLE log length {} does not match FRI message log length {}",
+            packed_log_len, expected_log_len
+        )));
+    }
+    Ok(())
+}
+
+/// Check that a serialized proof is no longer than `max_proof_bytes`
+fn check_proof_len(proof_len: usize, max_proof_bytes: usize) -> Result<(), FriVeilError> {
+    if proof_len > max_proof_bytes {
+        return Err(FriVeilError::InvalidInput(format!(
+            "Proof too large: {} bytes exceeds the maximum of {} for these parameters",
+            proof_len, max_proof_bytes
+        )));
+    }
+    Ok(())
+}
+
+/// Number of MLE variables committed under `fri_params`
+fn committed_n_vars(fri_params: &FRIParams<B128>) -> usize {
+    fri_params.rs_code().log_dim()
+        + fri_params.log_batch_size()
+        + <B128 as ExtensionField<B1>>::LOG_DEGREE
+}
+
+/// Check parameters an untrusted artifact declares against the verifier's `fri_params`
+///
+/// Bundles and audit records carry the parameters they were proven under. Verifying
+/// under parameters rebuilt from those values would let the artifact pick its own
+/// soundness, e.g. a single test query, so they must equal the verifier's instead.
+///
+/// # Returns
+///
+/// * `Ok(())` - Rate, query count and `n_vars` all match `fri_params`
+/// * `Err(FriVeilError::HeaderMismatch)` - Mismatch naming the differing parameter
+pub(crate) fn check_declared_params(
+    log_inv_rate: usize,
+    num_test_queries: usize,
+    n_vars: usize,
+    fri_params: &FRIParams<B128>,
+) -> Result<(), FriVeilError> {
+    ProofHeader::new(field_id::<B128>(), log_inv_rate, num_test_queries)?
+        .check_against(fri_params)?;
+    let expected_n_vars = committed_n_vars(fri_params);
+    if n_vars != expected_n_vars {
+        return Err(FriVeilError::HeaderMismatch {
+            field: "n_vars",
+            expected: expected_n_vars,
+            got: n_vars,
+        });
+    }
+    Ok(())
+}
+
+/// Check that an evaluation point has one coordinate per variable committed under `fri_params`
+fn check_evaluation_point_len(
+    point_len: usize,
+    fri_params: &FRIParams<B128>,
+) -> Result<(), FriVeilError> {
+    let expected_len = committed_n_vars(fri_params);
+    if point_len != expected_len {
+        return Err(FriVeilError::ParameterMismatch(format!(
+            "Evaluation point has {} coordinates but FRI parameters expect {}",
+            point_len, expected_len
+        )));
+    }
+    Ok(())
+}
+
+/// Copy out all unread bytes of a transcript without consuming it
+fn transcript_bytes(transcript: &VerifierTranscript<StdChallenger>) -> Vec<u8> {
+    let mut cloned = transcript.clone();
+    let mut message_reader = cloned.message();
+    let buffer = message_reader.buffer();
+    let remaining = buffer.remaining();
+
+    if remaining == 0 {
+        return Vec::new();
+    }
+
+    // Read all remaining bytes
+    let mut bytes = vec![0u8; remaining];
+    buffer.copy_to_slice(&mut bytes);
+    bytes
+}
+
+impl FriVeilUtils for FriVeilDefault {
+    /// Serialize a proof transcript in its canonical byte layout
+    ///
+    /// The layout is the order the prover wrote messages: [`ProofHeader`], length-prefixed
+    /// [`ProofBinding`], [`point_digest`], commitment root, then the binius PCS proof.
+    /// Every value is fixed-width with little-endian integers, and no step iterates a hash
+    /// map or depends on thread scheduling: Fiat-Shamir challenges are a function of the
+    /// messages alone, and parallel encoding and Merkle hashing write each output to a
+    /// fixed position. Proving the same MLE at the same point with the same parameters
+    /// therefore yields byte-identical proofs, with or without the `parallel` feature and
+    /// for any thread count.
+    fn get_transcript_bytes(&self, transcript: &VerifierTranscript<StdChallenger>) -> Vec<u8> {
+        transcript_bytes(transcript)
+    }
+    fn reconstruct_transcript_from_bytes(
+        &self,
+        bytes: Vec<u8>,
+    ) -> VerifierTranscript<StdChallenger> {
+        VerifierTranscript::new(StdChallenger::default(), bytes)
+    }
+
+    /// Build a verifier transcript from borrowed proof bytes of at most `max_proof_bytes`
+    ///
+    /// The length is checked before the bytes are copied into the transcript, so an
+    /// oversized proof costs no allocation. Pass [`FriVeil::max_proof_bytes`] for the
+    /// verifier's `fri_params` to reject anything larger than an honest proof can be.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Transcript over `bytes`
+    /// * `Err(FriVeilError)` - `bytes` is longer than `max_proof_bytes`
+    fn reconstruct_transcript_bounded(
+        &self,
+        bytes: &[u8],
+        max_proof_bytes: usize,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        check_proof_len(bytes.len(), max_proof_bytes)?;
+        Ok(self.reconstruct_transcript_from_bytes(bytes.to_vec()))
+    }
+
+    /// Build a verifier transcript from a proof arriving over a byte stream
+    ///
+    /// The [`ProofHeader`] is read and checked against `fri_params` as soon as its bytes
+    /// arrive, so a proof for another field or mismatched parameters is rejected without
+    /// draining the stream. The rest is read to EOF in whatever chunks the reader yields; the result
+    /// equals `reconstruct_transcript_from_bytes` on the whole proof. At most one byte
+    /// more than [`FriVeil::max_proof_bytes`] is buffered, so an endless or oversized
+    /// stream is rejected with bounded memory.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VerifierTranscript)` - Transcript over the full proof
+    /// * `Err(FriVeilError)` - Read failure, malformed header, parameter mismatch, or a
+    ///   proof larger than `max_proof_bytes`
+    fn reconstruct_transcript_from_reader<R: std::io::Read>(
+        &self,
+        mut reader: R,
+        fri_params: &FRIParams<B128>,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError> {
+        use std::io::Read;
+
+        let mut bytes = vec![0u8; ProofHeader::ENCODED_LEN];
+        reader
+            .read_exact(&mut bytes)
+            .map_err(|e| FriVeilError::Io(format!("Failed to read proof header: {}", e)))?;
+        ProofHeader::from_bytes(&bytes)?.check_against(fri_params)?;
+
+        let max_proof_bytes = self.max_proof_bytes(fri_params);
+        let remaining_limit = (max_proof_bytes - ProofHeader::ENCODED_LEN) as u64 + 1;
+        reader
+            .take(remaining_limit)
+            .read_to_end(&mut bytes)
+            .map_err(|e| FriVeilError::Io(format!("Failed to read proof: {}", e)))?;
+        check_proof_len(bytes.len(), max_proof_bytes)?;
+        Ok(self.reconstruct_transcript_from_bytes(bytes))
+    }
+
+    /// Compare two commitment roots in time independent of where they differ
+    ///
+    /// Only the lengths are compared early, since they are public. Used for every
+    /// expected-root and CID check during verification.
+    fn roots_equal_ct(&self, a: &[u8], b: &[u8]) -> bool {
+        ct_eq(a, b)
+    }
+
+    /// Package a commitment root, its parameters and one proof as a single artifact
+    ///
+    /// The result is a versioned [`ProofBundle`]; the verifier supplies the evaluation
+    /// point and claim, as it does for a bare proof.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - Encoded bundle
+    /// * `Err(FriVeilError)` - Root is not 32 bytes, or a parameter does not fit the format
+    fn bundle<P, C>(
+        &self,
+        commit_output: &CommitOutput<P, Vec<u8>, C>,
+        proof: &VerifierTranscript<StdChallenger>,
+        fri_params: &FRIParams<B128>,
+    ) -> Result<Vec<u8>, FriVeilError> {
+        let header = ProofHeader::for_params(fri_params)?;
+        let n_vars = committed_n_vars(fri_params);
+        let bundle = ProofBundle {
+            log_inv_rate: header.log_inv_rate,
+            num_test_queries: header.num_test_queries,
+            n_vars: u8::try_from(n_vars)
+                .map_err(|_| FriVeilError::InvalidLogLen(format!("n_vars {} too large", n_vars)))?,
+            root: Commitment::from_bytes(&commit_output.commitment)?.into(),
+            proof: transcript_bytes(proof),
+        };
+        Ok(bundle.to_bytes())
+    }
+
+    /// Split a bundle from [`Self::bundle`] back into root and proof
+    ///
+    /// The parameters a bundle declares are never trusted: they must equal the
+    /// verifier's `fri_params`, so a bundle can't lower the query count or change the
+    /// rate it is verified under. The proof is bounded by [`FriVeil::max_proof_bytes`]
+    /// before it is buffered into a transcript.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Encoded bundle
+    /// * `fri_params` - FRI parameters the verifier expects the proof to be generated with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(([u8; 32], VerifierTranscript))` - Root and proof
+    /// * `Err(FriVeilError)` - Malformed or truncated bundle, parameters other than
+    ///   `fri_params`, or an oversized proof
+    fn unbundle(
+        &self,
+        bytes: &[u8],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<([u8; 32], VerifierTranscript<StdChallenger>), FriVeilError> {
+        let bundle = ProofBundle::from_bytes(bytes)?;
+        check_declared_params(
+            bundle.log_inv_rate as usize,
+            bundle.num_test_queries as usize,
+            bundle.n_vars as usize,
+            fri_params,
+        )?;
+
+        let proof =
+            self.reconstruct_transcript_bounded(&bundle.proof, self.max_proof_bytes(fri_params))?;
+        Ok((bundle.root, proof))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::{
+        poly::{PackedMLE, Utils},
+        test_utils::{ProofFixture, create_test_data},
+    };
+    use binius_field::{Field, Random};
+    use binius_math::ntt::{NeighborsLastMultiThread, domain_context::GenericPreExpanded};
+    use binius_verifier::{
+        config::{B1, B128},
+        hash::{StdCompression, StdDigest},
+        merkle_tree::BinaryMerkleTreeScheme,
+    };
+    use rand::{SeedableRng, rngs::StdRng};
+    use std::iter::repeat_with;
+
+    type TestFriVeil = FriVeil<
+        'static,
+        B128,
+        BinaryMerkleTreeScheme<B128, StdDigest, StdCompression>,
+        NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    >;
+
+    #[test]
+    fn test_friveil_new() {
+        const LOG_INV_RATE: usize = 1;
+        const NUM_TEST_QUERIES: usize = 3;
+        const N_VARS: usize = 10;
+        const LOG_NUM_SHARES: usize = 2;
+
+        let friveil = TestFriVeil::new(LOG_INV_RATE, NUM_TEST_QUERIES, N_VARS, LOG_NUM_SHARES);
+
+        assert_eq!(friveil.log_inv_rate, LOG_INV_RATE);
+        assert_eq!(friveil.num_test_queries, NUM_TEST_QUERIES);
+        assert_eq!(friveil.n_vars, N_VARS);
+        assert_eq!(friveil.log_num_shares, LOG_NUM_SHARES);
+    }
+
+    #[test]
+    fn test_calculate_evaluation_point_random() {
+        const N_VARS: usize = 8;
+        let friveil = TestFriVeil::new(1, 3, N_VARS, 2);
+
+        let result = friveil.calculate_evaluation_point_random();
+        assert!(result.is_ok());
+
+        let evaluation_point = result.unwrap();
+        assert_eq!(evaluation_point.len(), N_VARS);
+
+        // Each call draws a fresh OS-seeded point
+        let result2 = friveil.calculate_evaluation_point_random();
+        assert!(result2.is_ok());
+        let evaluation_point2 = result2.unwrap();
+        assert_ne!(evaluation_point, evaluation_point2);
+    }
+
+    #[test]
+    fn test_field_conversion_methods() {
+        let friveil = TestFriVeil::new(1, 3, 8, 2);
+
+        // Test small to large field conversion
+        let small_field_values: Vec<B1> = vec![B1::ZERO, B1::ONE, B1::ZERO, B1::ONE];
+        let large_field_values: Vec<B128> = friveil.lift_small_to_large_field(&small_field_values);
+
+        assert_eq!(large_field_values.len(), small_field_values.len());
+        assert_eq!(large_field_values[0], B128::from(B1::ZERO));
+        assert_eq!(large_field_values[1], B128::from(B1::ONE));
+
+        // Test large to small field conversion
+        let test_large_values: Vec<B128> = vec![B128::from(42u128), B128::from(100u128)];
+        let converted_small: Vec<B1> =
+            friveil.large_field_mle_to_small_field_mle(&test_large_values);
+
+        // Each B128 should expand to 128 B1 elements
+        assert_eq!(converted_small.len(), test_large_values.len() * 128);
+    }
+
+    #[test]
+    fn test_initialize_fri_context() {
+        let friveil = TestFriVeil::new(1, 3, 12, 2);
+
+        // Create test data
+        let test_data = create_test_data(1024); // 1KB test data
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let result = friveil.initialize_fri_context(packed_mle_values.packed_mle.log_len());
+        assert!(result.is_ok());
+
+        let (fri_params, _ntt) = result.unwrap();
+
+        // Verify FRI parameters are reasonable
+        assert_eq!(fri_params.rs_code().log_inv_rate(), friveil.log_inv_rate);
+        assert_eq!(fri_params.n_test_queries(), friveil.num_test_queries);
+    }
+
+    #[test]
+    fn test_commit_and_inclusion_proofs() {
+        let friveil = TestFriVeil::new(1, 3, 12, 2);
+
+        // Create test data
+        let test_data = create_test_data(1024);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        // Test commit
+        let commit_result = friveil.commit(
+            packed_mle_values.packed_mle.clone(),
+            fri_params.clone(),
+            &ntt,
+        );
+        assert!(commit_result.is_ok());
+
+        let commit_output = commit_result.unwrap();
+        assert!(!commit_output.commitment.is_empty());
+        assert!(!commit_output.codeword.is_empty());
+
+        let commitment_bytes: [u8; 32] = commit_output
+            .commitment
+            .to_vec()
+            .try_into()
+            .expect("We know commitment size is 32 bytes");
+        // Test inclusion proofs for first few elements
+        for i in 0..std::cmp::min(5, commit_output.codeword.len()) {
+            let value = commit_output.codeword[i];
+
+            // Generate inclusion proof
+            let inclusion_proof_result = friveil.inclusion_proof(&commit_output.committed, i);
+            assert!(inclusion_proof_result.is_ok());
+
+            let mut inclusion_proof = inclusion_proof_result.unwrap();
+
+            // Verify inclusion proof
+            let verify_result = friveil.verify_inclusion_proof(
+                &mut inclusion_proof,
+                &[value],
+                i,
+                &fri_params,
+                commitment_bytes,
+            );
+            assert!(
+                verify_result.is_ok(),
+                "Inclusion proof verification failed for index {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_evaluation_claim() {
+        let test_data = create_test_data(1024 * 1024); // 1mb test data
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        let evaluation_point = friveil
+            .calculate_evaluation_point_from_seed([0; 32])
+            .expect("Failed to generate evaluation point");
+
+        let result =
+            friveil.calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point);
+        assert!(result.is_ok());
+
+        let evaluation_claim = result.unwrap();
+        // The evaluation claim should be a valid field element
+        assert_ne!(evaluation_claim, B128::default()); // Should not be zero for random inputs
+    }
+
+    #[test]
+    fn test_full_prove_verify_workflow() {
+        // Create test data
+        let test_data = create_test_data(1024 * 1024); // 2KB test data
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+        // Initialize FRI context
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        // Generate evaluation point
+        let evaluation_point = friveil
+            .calculate_evaluation_point_from_seed([0; 32])
+            .expect("Failed to generate evaluation point");
+
+        // Commit to MLE
+        let commit_output = friveil
+            .commit(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+            )
+            .expect("Failed to commit");
+
+        // Generate proof
+        let prove_result = friveil.prove(
+            packed_mle_values.packed_mle.clone(),
+            fri_params.clone(),
+            &ntt,
+            &commit_output,
+            &evaluation_point,
+        );
+        assert!(prove_result.is_ok());
+
+        let mut verifier_transcript = prove_result.unwrap();
+
+        // Calculate evaluation claim
+        let evaluation_claim = friveil
+            .calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point)
+            .expect("Failed to calculate evaluation claim");
+
+        // Verify proof
+        let verify_result = friveil.verify_evaluation(
+            &mut verifier_transcript,
+            evaluation_claim,
+            &evaluation_point,
+            &fri_params,
+        );
+        assert!(
+            verify_result.is_ok(),
+            "Verification failed: {:?}",
+            verify_result
+        );
+    }
+
+    #[test]
+    fn test_invalid_verification_fails() {
+        // Create test data
+        let test_data = create_test_data(512);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        let commit_output = friveil
+            .commit(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+            )
+            .expect("Failed to commit");
+
+        let evaluation_point = friveil
+            .calculate_evaluation_point_from_seed([0; 32])
+            .expect("Failed to generate evaluation point");
+
+        let mut verifier_transcript = friveil
+            .prove(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+                &commit_output,
+                &evaluation_point,
+            )
+            .expect("Failed to generate proof");
+
+        // Use wrong evaluation claim (should cause verification to fail)
+        let wrong_evaluation_claim = B128::from(42u128);
+
+        let verify_result = friveil.verify_evaluation(
+            &mut verifier_transcript,
+            wrong_evaluation_claim,
+            &evaluation_point,
+            &fri_params,
+        );
+
+        // Verification should fail with wrong claim
+        assert!(
+            verify_result.is_err(),
+            "Verification should fail with wrong evaluation claim"
+        );
+    }
+
+    #[test]
+    fn test_data_availability_sampling() {
+        use rand::seq::index::sample;
+        use tracing::Level;
+
+        // Initialize logging for the test
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(Level::DEBUG)
+            .with_test_writer()
+            .try_init();
+
+        // Create test data
+        let test_data = create_test_data(1024 * 1024); // 1MB test data
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        // Initialize FRI context
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        // Commit to MLE
+        let commit_output = friveil
+            .commit(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+            )
+            .expect("Failed to commit");
+
+        let total_samples = commit_output.codeword.len();
+        let sample_size = total_samples / 2;
+        let indices =
+            sample(&mut StdRng::from_seed([0; 32]), total_samples, sample_size).into_vec();
+        let commitment_bytes: [u8; 32] = commit_output
+            .commitment
+            .to_vec()
+            .try_into()
+            .expect("We know commitment size is 32 bytes");
+
+        let mut successful_samples = 0;
+        let mut failed_samples = Vec::new();
+
+        for &sample_index in indices.iter() {
+            match friveil.inclusion_proof(&commit_output.committed, sample_index) {
+                Ok(mut inclusion_proof) => {
+                    let value = commit_output.codeword[sample_index];
+                    match friveil.verify_inclusion_proof(
+                        &mut inclusion_proof,
+                        &[value],
+                        sample_index,
+                        &fri_params,
+                        commitment_bytes,
+                    ) {
+                        Ok(_) => {
+                            successful_samples += 1;
+                        }
+                        Err(e) => {
+                            failed_samples
+                                .push((sample_index, format!("Verification failed: {}", e)));
+                        }
+                    }
+                }
+                Err(e) => {
+                    failed_samples.push((
+                        sample_index,
+                        format!("Inclusion proof generation failed: {}", e),
+                    ));
+                }
+            }
+        }
+
+        assert_eq!(failed_samples.len(), 0, "Some samples failed verification");
+        assert_eq!(
+            successful_samples, sample_size,
+            "Not all samples were verified"
+        );
+
+        println!("Successfully verified {} samples", successful_samples);
+    }
+
+    #[test]
+    fn test_codeword_decode() {
+        // Create test data
+        let test_data = create_test_data(512);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        // Initialize FRI context
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        // Encode codeword
+        let encoded_codeword = friveil
+            .encode_codeword(&packed_mle_values.packed_values, fri_params.clone(), &ntt)
+            .expect("Failed to encode codeword");
+
+        // Decode codeword
+        let decoded_codeword = friveil
+            .decode_codeword(&encoded_codeword, fri_params.clone(), &ntt)
+            .expect("Failed to decode codeword");
+
+        // Verify decoded codeword matches original values
+        assert_eq!(
+            decoded_codeword, packed_mle_values.packed_values,
+            "Decoded codeword should match original packed values"
+        );
+
+        println!("✅ Codeword decode test passed");
+    }
+
+    #[test]
+    fn test_error_correction_reconstruction() {
+        use rand::seq::index::sample;
+
+        // Create test data
+        let test_data = create_test_data(2048);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        // Initialize FRI context
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        // Encode codeword
+        let encoded_codeword = friveil
+            .encode_codeword(&packed_mle_values.packed_values, fri_params.clone(), &ntt)
+            .expect("Failed to encode codeword");
+
+        // Corrupt the codeword
+        let mut corrupted_codeword = encoded_codeword.clone();
+        let total_elements = corrupted_codeword.len();
+        let corruption_percentage = 0.1;
+
+        // Corrupt random elements
+        let num_corrupted = (total_elements as f64 * corruption_percentage) as usize;
+        let mut rng = StdRng::seed_from_u64(42);
+        let corrupted_indices = sample(&mut rng, total_elements, num_corrupted).into_vec();
+
+        for &index in &corrupted_indices {
+            corrupted_codeword[index] = B128::zero();
+        }
+
+        // Verify corruption happened
+        assert_ne!(
+            corrupted_codeword, encoded_codeword,
+            "Codeword should be corrupted"
+        );
+
+        // Reconstruct corrupted codeword
+        friveil
+            .reconstruct_codeword_naive(&mut corrupted_codeword, &corrupted_indices)
+            .expect("Failed to reconstruct codeword");
+
+        // Verify reconstruction succeeded
+        assert_eq!(
+            corrupted_codeword, encoded_codeword,
+            "Reconstructed codeword should match original encoded codeword"
+        );
+
+        // Decode the reconstructed codeword to verify it's correct
+        let decoded_reconstructed = friveil
+            .decode_codeword(&corrupted_codeword, fri_params.clone(), &ntt)
+            .expect("Failed to decode reconstructed codeword");
+
+        // Verify decoded reconstructed codeword matches original values
+        assert_eq!(
+            decoded_reconstructed, packed_mle_values.packed_values,
+            "Decoded reconstructed codeword should match original packed values"
+        );
+
+        println!(
+            "✅ Error correction reconstruction test passed: {} elements, {:.1}% corruption",
+            total_elements,
+            corruption_percentage * 100.0
+        );
+    }
+
+    #[test]
+    fn test_subpoly_matches_full_evaluation() {
+        let test_data = create_test_data(2048);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        // Fix the second and fourth element variables (indices are in full-point numbering)
+        let log_scalar_bit_width = 7;
+        let fixed = [
+            (log_scalar_bit_width + 1, B128::ONE),
+            (log_scalar_bit_width + 3, B128::ZERO),
+        ];
+        let sub_mle = packed_mle_values
+            .subpoly(&fixed)
+            .expect("Failed to extract subpoly");
+        assert_eq!(sub_mle.total_n_vars, packed_mle_values.total_n_vars - 2);
+        assert_eq!(
+            sub_mle.packed_values.len(),
+            packed_mle_values.packed_values.len() / 4
+        );
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let sub_point: Vec<B128> = repeat_with(|| B128::random(&mut rng))
+            .take(sub_mle.total_n_vars)
+            .collect();
+
+        // Re-insert the fixed coordinates to obtain the equivalent full point
+        let mut full_point = sub_point.clone();
+        for &(var, value) in &fixed {
+            full_point.insert(var, value);
+        }
+
+        let sub_eval = friveil
+            .calculate_evaluation_claim(&sub_mle.packed_values, &sub_point)
+            .expect("Failed to evaluate subpoly");
+        let full_eval = friveil
+            .calculate_evaluation_claim(&packed_mle_values.packed_values, &full_point)
+            .expect("Failed to evaluate full poly");
+        assert_eq!(sub_eval, full_eval);
+
+        // Bit variables and non-boolean values cannot be fixed
+        assert!(packed_mle_values.subpoly(&[(3, B128::ONE)]).is_err());
+        assert!(
+            packed_mle_values
+                .subpoly(&[(log_scalar_bit_width, B128::from(2u128))])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_packed_log_len() {
+        let test_data = create_test_data(1024);
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+        let log_len = packed_mle_values.packed_mle.log_len();
+
+        assert!(friveil.check_packed_log_len(log_len).is_ok());
+
+        let err = friveil
+            .check_packed_log_len(log_len + 1)
+            .expect_err("Mismatched log length should be rejected");
+        assert!(
+            err.to_string()
+                .contains(&packed_mle_values.total_n_vars.to_string())
+        );
+        assert!(
+            err.to_string()
+                .contains(&(packed_mle_values.total_n_vars + 1).to_string())
+        );
+    }
+
+    #[test]
+    fn test_bytes_to_packed_mle_size_limits() {
+        let utils = Utils::<B128>::new();
+
+        // Every 16-byte chunk becomes one field element, nothing is dropped
+        let test_data = create_test_data(1024);
+        let packed_mle_values = utils
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        assert_eq!(packed_mle_values.packed_values.len(), 1024 / 16);
+        assert_eq!(packed_mle_values.total_n_vars, 6 + 7);
+        assert_eq!(
+            packed_mle_values.packed_values[1],
+            B128::from(u128::from_le_bytes(test_data[16..32].try_into().unwrap()))
+        );
+
+        // Sizes beyond the field/domain limit are rejected before any conversion
+        let max_bytes = utils.max_data_bytes();
+        assert!(utils.packed_log_len(max_bytes).is_ok());
+        let err = utils
+            .packed_log_len(max_bytes + 1)
+            .expect_err("Oversized data should be rejected");
+        assert_eq!(
+            err,
+            FriVeilError::DataTooLarge {
+                got: max_bytes + 1,
+                max: max_bytes
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_evaluation_with_root() {
+        let fixture = ProofFixture::new(512);
+        let root = fixture.root();
+
+        let mut transcript = fixture.prove();
+        let result = fixture.friveil.verify_evaluation_with_root(
+            &mut transcript,
+            root,
+            fixture.evaluation_claim,
+            &fixture.evaluation_point,
+            &fixture.fri_params,
+        );
+        assert!(result.is_ok(), "Verification failed: {:?}", result);
+
+        let mut wrong_root = root;
+        wrong_root[0] ^= 1;
+        let mut transcript = fixture.prove();
+        let err = fixture
+            .friveil
+            .verify_evaluation_with_root(
+                &mut transcript,
+                wrong_root,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Wrong root should be rejected");
+        assert!(matches!(err, FriVeilError::CommitmentMismatch(_)));
+    }
+
+    #[test]
+    fn test_verify_evaluation_with_root_hex() {
+        let fixture = ProofFixture::new(512);
+        let root_hex = to_hex(&fixture.root()).to_uppercase();
+
+        for hex in [root_hex.clone(), format!("0x{}", root_hex.to_lowercase())] {
+            let mut transcript = fixture.prove();
+            let result = fixture.friveil.verify_evaluation_with_root_hex(
+                &mut transcript,
+                &hex,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            );
+            assert!(result.is_ok(), "Verification failed: {:?}", result);
+        }
+
+        // Malformed hex never reaches FRI verification
+        let too_short = &root_hex[..62];
+        let too_long = format!("{}00", root_hex);
+        let non_hex = format!("zz{}", &root_hex[2..]);
+        let non_ascii = format!("é{}", &root_hex[2..]);
+        for hex in [
+            too_short,
+            too_long.as_str(),
+            non_hex.as_str(),
+            non_ascii.as_str(),
+            "",
+        ] {
+            assert!(
+                parse_commitment_hex(hex).is_err(),
+                "{:?} should be rejected",
+                hex
+            );
+            let mut transcript = fixture.prove();
+            assert!(
+                fixture
+                    .friveil
+                    .verify_evaluation_with_root_hex(
+                        &mut transcript,
+                        hex,
+                        fixture.evaluation_claim,
+                        &fixture.evaluation_point,
+                        &fixture.fri_params,
+                    )
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_committer_matches_individual_commits() {
+        let utils = Utils::<B128>::new();
+        let blobs: Vec<_> = (0..3u8)
+            .map(|seed| {
+                let data: Vec<u8> = (0..1024)
+                    .map(|i| (i as u8).wrapping_mul(seed + 1))
+                    .collect();
+                utils
+                    .bytes_to_packed_mle(&data)
+                    .expect("Failed to create packed MLE")
+            })
+            .collect();
+        let log_len = blobs[0].packed_mle.log_len();
+        let friveil = TestFriVeil::new(1, 3, blobs[0].total_n_vars, 3);
+
+        let batch = BatchCommitter::new(&friveil, log_len).expect("Failed to create committer");
+        for blob in &blobs {
+            let batched = batch
+                .commit(blob.packed_mle.clone())
+                .expect("Failed to batch commit");
+
+            let (fri_params, ntt) = friveil
+                .initialize_fri_context(log_len)
+                .expect("Failed to initialize FRI context");
+            let individual = friveil
+                .commit(blob.packed_mle.clone(), fri_params, &ntt)
+                .expect("Failed to commit");
+
+            assert_eq!(batched.commitment, individual.commitment);
+            assert_eq!(batched.codeword, individual.codeword);
+        }
+
+        // A blob of a different size can't reuse the context
+        let bigger = utils
+            .bytes_to_packed_mle(&create_test_data(2048))
+            .expect("Failed to create packed MLE");
+        assert!(batch.commit(bigger.packed_mle).is_err());
+    }
+
+    #[test]
+    fn test_prove_at_lagrange_matches_coordinate_form() {
+        let fixture = ProofFixture::new(512);
+        let lagrange_weights: Vec<B128> = eq_ind_partial_eval(&fixture.evaluation_point)
+            .as_ref()
+            .iter()
+            .copied()
+            .collect();
+
+        let lagrange_claim = fixture
+            .friveil
+            .calculate_evaluation_claim_lagrange(
+                &fixture.packed_mle_values.packed_values,
+                &lagrange_weights,
+            )
+            .expect("Failed to calculate Lagrange claim");
+        assert_eq!(lagrange_claim, fixture.evaluation_claim);
+
+        let (recovered_point, mut lagrange_transcript) = fixture
+            .friveil
+            .prove_at_lagrange(
+                fixture.packed_mle_values.packed_mle.clone(),
+                &lagrange_weights,
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+                &fixture.commit_output,
+            )
+            .expect("Failed to generate Lagrange-form proof");
+        assert_eq!(recovered_point, fixture.evaluation_point);
+
+        let mut coordinate_transcript = fixture.prove();
+        for transcript in [&mut lagrange_transcript, &mut coordinate_transcript] {
+            let result = fixture.friveil.verify_evaluation(
+                transcript,
+                lagrange_claim,
+                &recovered_point,
+                &fixture.fri_params,
+            );
+            assert!(result.is_ok(), "Verification failed: {:?}", result);
+        }
+
+        // Weights that are not a tensor-product basis are rejected
+        let mut bad_weights = lagrange_weights.clone();
+        bad_weights[1] += B128::ONE;
+        assert!(
+            fixture
+                .friveil
+                .lagrange_weights_to_point(&bad_weights)
+                .is_err()
+        );
+        assert!(
+            fixture
+                .friveil
+                .lagrange_weights_to_point(&lagrange_weights[1..])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_adversarial_inputs_return_errors() {
+        let fixture = ProofFixture::new(512);
+        let friveil = &fixture.friveil;
+
+        // Garbage and truncated proofs
+        let proof_bytes = friveil.get_transcript_bytes(&fixture.prove());
+        for bytes in [
+            vec![],
+            vec![0xff; 7],
+            proof_bytes[..32].to_vec(),
+            proof_bytes[..proof_bytes.len() / 2].to_vec(),
+        ] {
+            let mut transcript = friveil.reconstruct_transcript_from_bytes(bytes);
+            assert!(
+                friveil
+                    .verify_evaluation(
+                        &mut transcript,
+                        fixture.evaluation_claim,
+                        &fixture.evaluation_point,
+                        &fixture.fri_params,
+                    )
+                    .is_err()
+            );
+        }
+
+        // Evaluation point of the wrong dimension
+        let mut transcript = fixture.prove();
+        assert!(
+            friveil
+                .verify_evaluation(
+                    &mut transcript,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point[1..],
+                    &fixture.fri_params,
+                )
+                .is_err()
+        );
+        assert!(
+            friveil
+                .calculate_evaluation_claim(
+                    &fixture.packed_mle_values.packed_values,
+                    &fixture.evaluation_point[1..],
+                )
+                .is_err()
+        );
+        assert!(
+            friveil
+                .prove(
+                    fixture.packed_mle_values.packed_mle.clone(),
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                    &fixture.commit_output,
+                    &fixture.evaluation_point[1..],
+                )
+                .is_err()
+        );
+
+        // Out-of-range codeword positions
+        let codeword_len = fixture.commit_output.codeword.len();
+        let mut inclusion_proof = friveil
+            .inclusion_proof(&fixture.commit_output.committed, 0)
+            .expect("Failed to generate inclusion proof");
+        assert!(
+            friveil
+                .verify_inclusion_proof(
+                    &mut inclusion_proof,
+                    &[fixture.commit_output.codeword[0]],
+                    codeword_len,
+                    &fixture.fri_params,
+                    fixture.root(),
+                )
+                .is_err()
+        );
+        let mut codeword = fixture.commit_output.codeword.clone();
+        assert!(
+            friveil
+                .reconstruct_codeword_naive(&mut codeword, &[codeword_len])
+                .is_err()
+        );
+
+        // Codeword of the wrong length
+        assert!(
+            friveil
+                .decode_codeword(
+                    &fixture.commit_output.codeword[1..],
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_share_proofs_verify_independently() {
+        let fixture = ProofFixture::new(512);
+        let root = fixture.root();
+        let num_shares = fixture.commit_output.codeword.len();
+
+        let share_proofs: Vec<ShareProof> = (0..num_shares)
+            .map(|index| {
+                fixture
+                    .friveil
+                    .share_proof(&fixture.commit_output, index)
+                    .expect("Failed to extract share proof")
+            })
+            .collect();
+
+        for (index, share_proof) in share_proofs.iter().enumerate() {
+            assert_eq!(share_proof.value, fixture.commit_output.codeword[index]);
+            let result =
+                fixture
+                    .friveil
+                    .verify_share_proof(root, index, share_proof, &fixture.fri_params);
+            assert!(result.is_ok(), "Share {} failed: {:?}", index, result);
+        }
+
+        // A proof doesn't verify at another position or with a different value
+        let mut tampered = share_proofs[0].clone();
+        tampered.value += B128::ONE;
+        assert!(
+            fixture
+                .friveil
+                .verify_share_proof(root, 0, &tampered, &fixture.fri_params)
+                .is_err()
+        );
+        assert!(
+            fixture
+                .friveil
+                .verify_share_proof(root, 1, &share_proofs[0], &fixture.fri_params)
+                .is_err()
+        );
+        assert!(
+            fixture
+                .friveil
+                .share_proof(&fixture.commit_output, num_shares)
+                .is_err()
+        );
+    }
 
-        if data.len() != expected_data_len {
-            return Err(format!(
-                "Unexpected data length: {} {} ",
-                expected_data_len,
-                data.len()
-            ));
+    #[test]
+    fn test_share_proofs_match_single_extraction() {
+        let fixture = ProofFixture::new(4096);
+        let root = fixture.root();
+        let indices: Vec<usize> = (0..fixture.commit_output.codeword.len()).rev().collect();
+
+        let share_proofs = fixture
+            .friveil
+            .share_proofs(&fixture.commit_output, &indices)
+            .expect("Failed to extract share proofs");
+        assert_eq!(share_proofs.len(), indices.len());
+        for (&index, share_proof) in indices.iter().zip(&share_proofs) {
+            let single = fixture
+                .friveil
+                .share_proof(&fixture.commit_output, index)
+                .unwrap();
+            assert_eq!(share_proof, &single);
+            assert!(
+                fixture
+                    .friveil
+                    .verify_share_proof(root, index, share_proof, &fixture.fri_params)
+                    .is_ok()
+            );
         }
 
-        let _scope = tracing::trace_span!(
-            "Reed-Solomon encode",
-            log_len = log_len,
-            log_batch_size = log_batch_size,
-        )
-        .entered();
+        #[cfg(feature = "parallel")]
+        {
+            let single_threaded = rayon::ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .unwrap()
+                .install(|| {
+                    fixture
+                        .friveil
+                        .share_proofs(&fixture.commit_output, &indices)
+                        .unwrap()
+                });
+            assert_eq!(single_threaded, share_proofs);
+        }
 
-        let data_portion_len = data.len();
+        assert!(
+            fixture
+                .friveil
+                .share_proofs(&fixture.commit_output, &[indices.len()])
+                .is_err()
+        );
+    }
 
-        for i in 0..data_portion_len {
-            output[i].write(data[i]);
+    #[test]
+    fn test_commit_sparse_matches_dense_commit() {
+        // 90% zero blob: only the first tenth carries data
+        let mut test_data = vec![0u8; 2048];
+        test_data[..204].copy_from_slice(&create_test_data(204));
+        let dense = Utils::<B128>::new()
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        let nonzero: Vec<(usize, B128)> = dense
+            .packed_values
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, value)| *value != B128::zero())
+            .collect();
+
+        let friveil = TestFriVeil::new(1, 3, dense.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(dense.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+
+        let dense_commit = friveil
+            .commit(dense.packed_mle.clone(), fri_params.clone(), &ntt)
+            .expect("Failed to commit");
+        let sparse_commit = friveil
+            .commit_sparse(&nonzero, dense.total_n_vars, fri_params.clone(), &ntt)
+            .expect("Failed to commit sparse");
+        assert_eq!(sparse_commit.commitment, dense_commit.commitment);
+        assert_eq!(sparse_commit.codeword, dense_commit.codeword);
+
+        let sparse = Utils::<B128>::new()
+            .sparse_to_packed_mle(&nonzero, dense.total_n_vars)
+            .expect("Failed to create sparse packed MLE");
+        assert_eq!(sparse.packed_values, dense.packed_values);
+
+        let out_of_range = [(dense.packed_values.len(), B128::ONE)];
+        assert!(
+            friveil
+                .commit_sparse(&out_of_range, dense.total_n_vars, fri_params, &ntt)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_mismatched_num_test_queries() {
+        let fixture = ProofFixture::new(512);
+        let log_len = fixture.packed_mle_values.packed_mle.log_len();
+        let total_n_vars = fixture.packed_mle_values.total_n_vars;
+
+        // Fixture proves with 3 queries; verifiers configured for more and fewer
+        for verifier_queries in [4, 2] {
+            let verifier = TestFriVeil::new(1, verifier_queries, total_n_vars, 3);
+            let (verifier_params, _) = verifier
+                .initialize_fri_context(log_len)
+                .expect("Failed to initialize FRI context");
+
+            let mut transcript = fixture.prove();
+            let err = verifier
+                .verify_evaluation(
+                    &mut transcript,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &verifier_params,
+                )
+                .expect_err("Mismatched query count should be rejected");
+            assert_eq!(
+                err,
+                FriVeilError::HeaderMismatch {
+                    field: "num_test_queries",
+                    expected: verifier_queries,
+                    got: 3,
+                }
+            );
         }
+    }
 
-        for i in data_portion_len..output.len() {
-            output[i].write(P::Scalar::zero());
+    #[test]
+    fn test_mle_eval_matches_evaluation_claim() {
+        let test_data = create_test_data(512);
+        let utils = Utils::<B128>::new();
+        let packed_mle_values = utils
+            .bytes_to_packed_mle(&test_data)
+            .expect("Failed to create packed MLE");
+        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..4 {
+            let point: Vec<B128> = repeat_with(|| B128::random(&mut rng))
+                .take(packed_mle_values.total_n_vars)
+                .collect();
+            let direct = utils
+                .mle_eval(&packed_mle_values, &point)
+                .expect("Failed to evaluate MLE");
+            let claim = friveil
+                .calculate_evaluation_claim(&packed_mle_values.packed_values, &point)
+                .expect("Failed to calculate evaluation claim");
+            assert_eq!(direct, claim);
         }
 
-        let output_initialized =
-            unsafe { uninit::out_ref::Out::<[P::Scalar]>::from(output).assume_init() };
-        let mut code = FieldSliceMut::from_slice(log_len + log_batch_size, output_initialized)
-            .map_err(|e| e.to_string())?;
+        assert!(utils.mle_eval(&packed_mle_values, &[B128::ONE]).is_err());
+    }
 
-        let skip_early = log_inv;
-        let skip_late = log_batch_size;
+    #[test]
+    fn test_commit_output_memory_footprint_scales() {
+        let small = ProofFixture::new(512);
+        let large = ProofFixture::new(1024);
+        assert_eq!(
+            large.packed_mle_values.total_n_vars,
+            small.packed_mle_values.total_n_vars + 1
+        );
 
-        // TODO: create an optimised version PR to binius 64 for inverse_ntt
-        let log_d = code.log_len();
-        use binius_math::ntt::DomainContext;
-        for layer in (skip_early..(log_d - skip_late)).rev() {
-            let num_blocks = 1 << layer;
-            let block_size_half = 1 << (log_d - layer - 1);
-            for block in 0..num_blocks {
-                let twiddle = ntt.domain_context().twiddle(layer, block);
-                let block_start = block << (log_d - layer);
-                for idx0 in block_start..(block_start + block_size_half) {
-                    let idx1 = block_size_half | idx0;
-                    // perform butterfly
-                    let mut u = code.get(idx0);
-                    let mut v = code.get(idx1);
+        let small_footprint = small.commit_output.memory_footprint(&small.fri_params);
+        let large_footprint = large.commit_output.memory_footprint(&large.fri_params);
+        let codeword_bytes = small.commit_output.codeword.len() * std::mem::size_of::<B128>();
+        assert!(small_footprint >= codeword_bytes);
+        // One more variable doubles the codeword and tree, plus or minus the fixed root
+        assert!(large_footprint.abs_diff(2 * small_footprint) <= 64);
 
-                    v += u;
-                    u += v * twiddle;
-                    code.set(idx0, u);
-                    code.set(idx1, v);
-                }
+        // Clearing the codeword frees only the codeword; the tree is still counted
+        let mut cleared = small;
+        cleared.commit_output.codeword = Vec::new();
+        assert_eq!(
+            cleared.commit_output.memory_footprint(&cleared.fri_params),
+            small_footprint - codeword_bytes
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_transcript_from_chunked_reader() {
+        /// Reader yielding at most `chunk_size` bytes per call, like a slow socket
+        struct ChunkedReader<'b> {
+            bytes: &'b [u8],
+            chunk_size: usize,
+        }
+
+        impl std::io::Read for ChunkedReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = buf.len().min(self.chunk_size).min(self.bytes.len());
+                buf[..n].copy_from_slice(&self.bytes[..n]);
+                self.bytes = &self.bytes[n..];
+                Ok(n)
             }
         }
 
-        Ok(())
+        let fixture = ProofFixture::new(512);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let proof_bytes = friveil.get_transcript_bytes(&fixture.prove());
+
+        let reader = ChunkedReader {
+            bytes: &proof_bytes,
+            chunk_size: 7,
+        };
+        let mut transcript = friveil
+            .reconstruct_transcript_from_reader(reader, &fixture.fri_params)
+            .expect("Failed to reconstruct transcript");
+        assert_eq!(friveil.get_transcript_bytes(&transcript), proof_bytes);
+
+        let result = friveil.verify_evaluation(
+            &mut transcript,
+            fixture.evaluation_claim,
+            &fixture.evaluation_point,
+            &fixture.fri_params,
+        );
+        assert!(result.is_ok(), "Verification failed: {:?}", result);
+
+        // A stream ending inside the header is rejected
+        let truncated = ChunkedReader {
+            bytes: &proof_bytes[..2],
+            chunk_size: 7,
+        };
+        assert!(
+            friveil
+                .reconstruct_transcript_from_reader(truncated, &fixture.fri_params)
+                .is_err()
+        );
     }
-}
 
-impl FriVeilUtils for FriVeilDefault {
-    fn get_transcript_bytes(&self, transcript: &VerifierTranscript<StdChallenger>) -> Vec<u8> {
-        let mut cloned = transcript.clone();
-        let mut message_reader = cloned.message();
-        let buffer = message_reader.buffer();
-        let remaining = buffer.remaining();
+    #[test]
+    fn test_verify_rejects_proof_for_other_field() {
+        let fixture = ProofFixture::new(512);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+
+        // Re-tag the proof as if it were generated over B64
+        let mut proof_bytes = friveil.get_transcript_bytes(&fixture.prove());
+        proof_bytes[1] = 6;
+
+        let mut transcript = friveil.reconstruct_transcript_from_bytes(proof_bytes.clone());
+        let err = friveil
+            .verify_evaluation(
+                &mut transcript,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Proof for another field should be rejected");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::HeaderMismatch {
+                    field: "field_id",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+
+        let err = friveil
+            .reconstruct_transcript_from_reader(proof_bytes.as_slice(), &fixture.fri_params)
+            .expect_err("Proof for another field should be rejected");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::HeaderMismatch {
+                    field: "field_id",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_mismatched_log_inv_rate() {
+        let fixture = ProofFixture::new(512);
+        let rate_2 = TestFriVeil::new(2, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let (rate_2_params, rate_2_ntt) = rate_2
+            .initialize_fri_context(fixture.packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+        let rate_2_commit = rate_2
+            .commit(
+                fixture.packed_mle_values.packed_mle.clone(),
+                rate_2_params.clone(),
+                &rate_2_ntt,
+            )
+            .expect("Failed to commit");
+        let mut transcript = rate_2
+            .prove(
+                fixture.packed_mle_values.packed_mle.clone(),
+                rate_2_params,
+                &rate_2_ntt,
+                &rate_2_commit,
+                &fixture.evaluation_point,
+            )
+            .expect("Failed to generate proof");
+
+        // Fixture parameters use log_inv_rate = 1
+        let err = fixture
+            .friveil
+            .verify_evaluation(
+                &mut transcript,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Rate-2 proof should be rejected by a rate-1 verifier");
+        assert_eq!(
+            err,
+            FriVeilError::HeaderMismatch {
+                field: "log_inv_rate",
+                expected: 1,
+                got: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_random_packed_mle_is_deterministic() {
+        let utils = Utils::<B128>::new();
+        let first = utils
+            .random_packed_mle(12, 7)
+            .expect("Failed to generate MLE");
+        let second = utils
+            .random_packed_mle(12, 7)
+            .expect("Failed to generate MLE");
+        let other_seed = utils
+            .random_packed_mle(12, 8)
+            .expect("Failed to generate MLE");
+
+        assert_eq!(first.total_n_vars, 12);
+        assert_eq!(first.packed_values.len(), 1 << 5);
+        assert_eq!(first.packed_mle.log_len(), 5);
+        assert_eq!(first.packed_values, second.packed_values);
+        assert_ne!(first.packed_values, other_seed.packed_values);
+        assert!(utils.random_packed_mle(6, 7).is_err());
+    }
+
+    #[test]
+    fn test_commit_fixed_cell_sizes() {
+        let friveil = FriVeilDefault::new(1, 3, 12, 3);
+        // 2^9 byte cells hold 2^5 elements
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(5)
+            .expect("Failed to initialize FRI context");
+        let cell = create_test_data(512);
+
+        let exact = friveil
+            .commit_fixed_cell(&cell, 9, fri_params.clone(), &ntt)
+            .expect("Exact cell should commit");
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&cell)
+            .expect("Failed to create packed MLE");
+        let dense = friveil
+            .commit(packed_mle_values.packed_mle, fri_params.clone(), &ntt)
+            .expect("Failed to commit");
+        assert_eq!(exact.commitment, dense.commitment);
+
+        let undersized = friveil.commit_fixed_cell(&cell[..511], 9, fri_params.clone(), &ntt);
+        assert!(matches!(undersized, Err(FriVeilError::InvalidInput(_))));
+        let oversized = create_test_data(513);
+        assert!(
+            friveil
+                .commit_fixed_cell(&oversized, 9, fri_params.clone(), &ntt)
+                .is_err()
+        );
+        assert!(
+            friveil
+                .commit_fixed_cell(&cell[..8], 3, fri_params, &ntt)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_degenerate_sizes_rejected() {
+        // A constant polynomial has no representation and is rejected outright
+        let constant = TestFriVeil::new(1, 3, 0, 2);
+        let err = constant
+            .initialize_fri_context(0)
+            .expect_err("total_n_vars == 0 should be rejected");
+        assert!(matches!(err, FriVeilError::InvalidLogLen(_)), "{}", err);
+
+        // A single element (16 bytes or less of input) is below the minimum
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&create_test_data(16))
+            .expect("Failed to create packed MLE");
+        assert_eq!(packed_mle_values.packed_mle.log_len(), 0);
+        let single = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 2);
+        let err = single
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect_err("Single-element MLE should be rejected");
+        assert!(matches!(err, FriVeilError::InvalidLogLen(_)), "{}", err);
+
+        assert_eq!(MIN_TOTAL_N_VARS, 8);
+        let minimal = TestFriVeil::new(1, 3, MIN_TOTAL_N_VARS, 2);
+        assert!(minimal.initialize_fri_context(MIN_PACKED_LOG_LEN).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_bound_proof_rejected_under_other_epoch() {
+        let fixture = ProofFixture::new(512);
+        let epoch_5 = ProofBinding::new().with_epoch(5);
+        let verify = |binding: &ProofBinding| {
+            let mut transcript = fixture.prove_bound(&epoch_5);
+            fixture.friveil.verify_evaluation_bound(
+                &mut transcript,
+                binding,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
+
+        assert!(verify(&epoch_5).is_ok());
+        let err = verify(&ProofBinding::new().with_epoch(6))
+            .expect_err("Epoch 5 proof should fail under epoch 6");
+        assert!(
+            matches!(err, FriVeilError::BindingMismatch { field: "epoch", .. }),
+            "{}",
+            err
+        );
+
+        // Plain verification expects an unbound proof
+        let mut transcript = fixture.prove_bound(&epoch_5);
+        assert!(
+            fixture
+                .friveil
+                .verify_evaluation(
+                    &mut transcript,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &fixture.fri_params,
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_verify_with_cid_rejects_mismatch_early() {
+        let fixture = ProofFixture::new(512);
+        let cid = fixture.commit_output.cid();
+        assert_eq!(&cid[..4], &[0x01, 0x55, 0x12, 0x20]);
+        assert_eq!(cid.len(), 36);
+
+        let mut transcript = fixture.prove();
+        assert!(
+            fixture
+                .friveil
+                .verify_evaluation_with_cid(
+                    &mut transcript,
+                    &cid,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &fixture.fri_params,
+                )
+                .is_ok()
+        );
+
+        // A wrong claim would fail FRI, so the CID error shows the check runs first
+        let mut other_cid = cid.clone();
+        other_cid[35] ^= 1;
+        let mut transcript = fixture.prove();
+        let err = fixture
+            .friveil
+            .verify_evaluation_with_cid(
+                &mut transcript,
+                &other_cid,
+                fixture.evaluation_claim + B128::ONE,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Mismatched CID should be rejected");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
+        );
+    }
 
-        if remaining == 0 {
-            return Vec::new();
+    #[test]
+    fn test_text_round_trip() {
+        let utils = Utils::<B128>::new();
+        for text in ["", "hello", "naïve café 数据可用性 🦀", "trailing nul\0\0"] {
+            let packed_mle = utils
+                .text_to_packed_mle(text)
+                .expect("Failed to convert text");
+            assert_eq!(utils.packed_mle_to_text(&packed_mle).unwrap(), text);
         }
 
-        // Read all remaining bytes
-        let mut bytes = vec![0u8; remaining];
-        buffer.copy_to_slice(&mut bytes);
-        bytes
+        // A prefix claiming more bytes than the MLE holds is rejected
+        let mut packed_mle = utils.text_to_packed_mle("short").unwrap();
+        packed_mle.packed_values[0] = B128::from(u64::MAX as u128);
+        assert!(matches!(
+            utils.packed_mle_to_text(&packed_mle),
+            Err(FriVeilError::InvalidInput(_))
+        ));
     }
-    fn reconstruct_transcript_from_bytes(
-        &self,
-        bytes: Vec<u8>,
-    ) -> VerifierTranscript<StdChallenger> {
-        VerifierTranscript::new(StdChallenger::default(), bytes)
+
+    #[test]
+    fn test_verify_evaluation_many_reports_breakdown() {
+        let fixture = ProofFixture::new(512);
+        let valid = fixture.friveil.get_transcript_bytes(&fixture.prove());
+        let mut bad_version = valid.clone();
+        bad_version[0] ^= 0xff;
+        let truncated = valid[..valid.len() / 2].to_vec();
+
+        let claim = fixture.evaluation_claim;
+        let point = fixture.evaluation_point.as_slice();
+        let batch: Vec<(&[u8], B128, &[B128])> = vec![
+            (valid.as_slice(), claim, point),
+            (bad_version.as_slice(), claim, point),
+            (valid.as_slice(), claim, point),
+            (truncated.as_slice(), claim, point),
+            (valid.as_slice(), claim + B128::ONE, point),
+        ];
+
+        let result = fixture
+            .friveil
+            .verify_evaluation_many(&batch, &fixture.fri_params);
+        assert_eq!(result.passed, 2);
+        assert!(!result.all_passed());
+        let failed: Vec<usize> = result.failed.iter().map(|(index, _)| *index).collect();
+        assert_eq!(failed, vec![1, 3, 4]);
+        assert!(matches!(
+            result.failed[0].1,
+            FriVeilError::HeaderMismatch {
+                field: "proof format version",
+                ..
+            }
+        ));
     }
-}
 
-#[cfg(test)]
-mod tests {
+    #[test]
+    fn test_coverage_recoverable_at_threshold() {
+        let fixture = ProofFixture::new(512);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let codeword_len = 1 << fixture.fri_params.rs_code().log_len();
+
+        // 32 data elements at rate 1/2: any 32 of the 64 positions suffice
+        let mut indices: Vec<usize> = (0..codeword_len).step_by(2).skip(1).collect();
+        let report = friveil.coverage(&indices, &fixture.fri_params).unwrap();
+        assert_eq!((report.held, report.required), (31, 32));
+        assert!(!report.recoverable);
+        assert!(report.recoverable_bytes.is_empty());
+        assert_eq!(report.missing(), 1);
+
+        indices.push(indices[0]);
+        assert!(
+            !friveil
+                .coverage(&indices, &fixture.fri_params)
+                .unwrap()
+                .recoverable
+        );
 
-    use super::*;
+        indices.push(codeword_len - 1);
+        let report = friveil.coverage(&indices, &fixture.fri_params).unwrap();
+        assert!(report.recoverable);
+        assert_eq!(report.recoverable_bytes, 0..512);
+        assert_eq!(report.missing(), 0);
 
-    use crate::poly::Utils;
-    use binius_field::Field;
-    use binius_math::ntt::{NeighborsLastMultiThread, domain_context::GenericPreExpanded};
-    use binius_verifier::{
-        config::{B1, B128},
-        hash::{StdCompression, StdDigest},
-        merkle_tree::BinaryMerkleTreeScheme,
-    };
+        assert!(
+            friveil
+                .coverage(&[codeword_len], &fixture.fri_params)
+                .is_err()
+        );
+    }
 
-    type TestFriVeil = FriVeil<
-        'static,
-        B128,
-        BinaryMerkleTreeScheme<B128, StdDigest, StdCompression>,
-        NeighborsLastMultiThread<GenericPreExpanded<B128>>,
-    >;
+    #[test]
+    fn test_block_sampling_across_three_commitments() {
+        let fixture = ProofFixture::new(512);
+        let commits: Vec<_> = (0..3u8)
+            .map(|blob| {
+                let data: Vec<u8> = (0..512).map(|i| (i as u8).wrapping_add(blob)).collect();
+                let packed_mle_values = Utils::<B128>::new().bytes_to_packed_mle(&data).unwrap();
+                fixture
+                    .friveil
+                    .commit(
+                        packed_mle_values.packed_mle,
+                        fixture.fri_params.clone(),
+                        &fixture.ntt,
+                    )
+                    .expect("Failed to commit")
+            })
+            .collect();
+        let roots: Vec<[u8; 32]> = commits
+            .iter()
+            .map(|commit| commit.commitment.clone().try_into().unwrap())
+            .collect();
+
+        let proof = fixture
+            .friveil
+            .prove_block_sampling(&commits, 48, &[7; 32], &fixture.fri_params)
+            .expect("Failed to prove block sampling");
+        assert_eq!(proof.shares.len(), 48);
+        assert!(
+            fixture
+                .friveil
+                .verify_block_sampling(&roots, 48, &[7; 32], &proof, &fixture.fri_params)
+                .is_ok()
+        );
+
+        // Samples land in every commitment
+        let codeword_len = 1 << fixture.fri_params.rs_code().log_len();
+        let positions = block_sample_positions(&roots, &[7; 32], codeword_len, 48).unwrap();
+        assert!((0..3).all(|c| positions.iter().any(|&(commitment, _)| commitment == c)));
+
+        // Another nonce samples other positions, so the proof does not answer it
+        assert_ne!(
+            block_sample_positions(&roots, &[8; 32], codeword_len, 48).unwrap(),
+            positions
+        );
+        assert!(
+            fixture
+                .friveil
+                .verify_block_sampling(&roots, 48, &[8; 32], &proof, &fixture.fri_params)
+                .is_err()
+        );
 
-    fn create_test_data(size_bytes: usize) -> Vec<u8> {
-        (0..size_bytes).map(|i| (i % 256) as u8).collect()
+        // Reordered roots derive different positions, and fewer samples are rejected
+        let reordered = [roots[1], roots[0], roots[2]];
+        assert!(
+            fixture
+                .friveil
+                .verify_block_sampling(&reordered, 48, &[7; 32], &proof, &fixture.fri_params)
+                .is_err()
+        );
+        assert!(
+            fixture
+                .friveil
+                .verify_block_sampling(&roots, 64, &[7; 32], &proof, &fixture.fri_params)
+                .is_err()
+        );
     }
 
     #[test]
-    fn test_friveil_new() {
-        const LOG_INV_RATE: usize = 1;
-        const NUM_TEST_QUERIES: usize = 3;
-        const N_VARS: usize = 10;
-        const LOG_NUM_SHARES: usize = 2;
+    fn test_verify_rejects_swapped_evaluation_point() {
+        let fixture = ProofFixture::new(512);
+        let mut swapped_point = fixture.evaluation_point.clone();
+        swapped_point.swap(0, 1);
+        let swapped_claim = fixture
+            .friveil
+            .calculate_evaluation_claim(&fixture.packed_mle_values.packed_values, &swapped_point)
+            .unwrap();
+
+        // Even with the claim that is correct for the swapped point
+        let mut transcript = fixture.prove();
+        let err = fixture
+            .friveil
+            .verify_evaluation(
+                &mut transcript,
+                swapped_claim,
+                &swapped_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Proof for another point should be rejected");
+        assert_eq!(err, FriVeilError::PointMismatch);
+    }
 
-        let friveil = TestFriVeil::new(LOG_INV_RATE, NUM_TEST_QUERIES, N_VARS, LOG_NUM_SHARES);
+    #[test]
+    fn test_pruned_commit_output_keeps_root_but_cannot_prove() {
+        let mut fixture = ProofFixture::new(512);
+        let root = fixture.root();
 
-        assert_eq!(friveil.log_inv_rate, LOG_INV_RATE);
-        assert_eq!(friveil.num_test_queries, NUM_TEST_QUERIES);
-        assert_eq!(friveil.n_vars, N_VARS);
-        assert_eq!(friveil.log_num_shares, LOG_NUM_SHARES);
+        // Pruning keeps only the root, which still compares equal to the original
+        let pruned = fixture
+            .friveil
+            .commit(
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+            )
+            .expect("Failed to commit")
+            .prune()
+            .expect("Failed to prune");
+        assert_eq!(pruned.as_bytes(), &root);
+        assert_eq!(
+            pruned,
+            Commitment::from(
+                fixture
+                    .commit_output
+                    .root()
+                    .expect("Root should be 32 bytes")
+            )
+        );
+
+        // An output whose codeword was cleared in place is refused with a typed error
+        fixture.commit_output.codeword = Vec::new();
+        let err = fixture
+            .friveil
+            .prove(
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+                &fixture.commit_output,
+                &fixture.evaluation_point,
+            )
+            .expect_err("Pruned output should not prove");
+        assert!(matches!(err, FriVeilError::CodewordPruned), "{}", err);
+        assert!(
+            fixture
+                .friveil
+                .share_proof(&fixture.commit_output, 0)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_reconstruction_bytes_matches_reconstruction() {
+        for log_inv_rate in [1, 2] {
+            let fixture = ProofFixture::with_log_inv_rate(512, log_inv_rate);
+            let friveil = &fixture.friveil;
+            let reconstruction_bytes = friveil.reconstruction_bytes(&fixture.fri_params);
+            let encoded_codeword = friveil
+                .encode_codeword(
+                    &fixture.packed_mle_values.packed_values,
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                )
+                .expect("Failed to encode codeword");
+
+            // Rebuild the codeword from its first `held` shares
+            let reconstruct = |held: usize| {
+                let missing: Vec<usize> = (held..encoded_codeword.len()).collect();
+                let mut codeword = encoded_codeword.clone();
+                for &index in &missing {
+                    codeword[index] = B128::zero();
+                }
+                friveil
+                    .reconstruct_codeword_naive(&mut codeword, &missing)
+                    .expect("Failed to reconstruct codeword");
+                codeword
+            };
+
+            // The code's dimension is the codeword length divided by the expansion factor
+            let held = encoded_codeword.len() >> log_inv_rate;
+            assert_eq!(reconstruct(held), encoded_codeword);
+            assert_ne!(
+                reconstruct(held - 1),
+                encoded_codeword,
+                "One share below the threshold should not reconstruct at rate {}",
+                log_inv_rate
+            );
+
+            assert_eq!(reconstruction_bytes, held * BYTES_PER_ELEMENT);
+            assert_eq!(reconstruction_bytes, 512);
+        }
     }
 
     #[test]
-    fn test_calculate_evaluation_point_random() {
-        const N_VARS: usize = 8;
-        let friveil = TestFriVeil::new(1, 3, N_VARS, 2);
-
-        let result = friveil.calculate_evaluation_point_random();
-        assert!(result.is_ok());
+    fn test_concat_packed_mles_matches_packing_concatenated_bytes() {
+        let utils = Utils::<B128>::new();
+        let data = create_test_data(1024);
+        let segments: Vec<_> = [&data[..512], &data[512..768], &data[768..]]
+            .iter()
+            .map(|segment| utils.bytes_to_packed_mle(segment).unwrap())
+            .collect();
 
-        let evaluation_point = result.unwrap();
-        assert_eq!(evaluation_point.len(), N_VARS);
+        let combined = utils
+            .concat_packed_mles(&segments)
+            .expect("Failed to concatenate segments");
+        let direct = utils.bytes_to_packed_mle(&data).unwrap();
+        assert_eq!(combined.packed_values, direct.packed_values);
+        assert_eq!(combined.total_n_vars, direct.total_n_vars);
+
+        // A 32-element segment after a 16-element one is not aligned to its size
+        let small = utils.bytes_to_packed_mle(&data[..256]).unwrap();
+        let large = utils.bytes_to_packed_mle(&data[..512]).unwrap();
+        assert!(utils.concat_packed_mles(&[small, large]).is_err());
+        assert!(utils.concat_packed_mles(&[]).is_err());
+    }
 
-        // Test deterministic behavior with fixed seed
-        let result2 = friveil.calculate_evaluation_point_random();
-        assert!(result2.is_ok());
-        let evaluation_point2 = result2.unwrap();
-        assert_eq!(evaluation_point, evaluation_point2);
+    #[test]
+    fn test_roots_equal_ct() {
+        let friveil = FriVeilDefault::new(1, 3, 12, 3);
+        let root = [0xabu8; 32];
+        let mut last_differs = root;
+        last_differs[31] ^= 1;
+        let mut first_differs = root;
+        first_differs[0] ^= 0x80;
+
+        assert!(friveil.roots_equal_ct(&root, &root));
+        assert!(!friveil.roots_equal_ct(&root, &last_differs));
+        assert!(!friveil.roots_equal_ct(&root, &first_differs));
+        assert!(!friveil.roots_equal_ct(&root, &root[..31]));
+        assert!(friveil.roots_equal_ct(&[], &[]));
     }
 
     #[test]
-    fn test_field_conversion_methods() {
-        let friveil = TestFriVeil::new(1, 3, 8, 2);
+    fn test_root_inclusion_in_aggregate() {
+        let roots: Vec<[u8; 32]> = (0..8u8).map(|i| [i.wrapping_mul(37); 32]).collect();
+        // 8 roots are 16 elements: 4 element variables plus 7 bit variables
+        let friveil = FriVeilDefault::new(1, 3, 11, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(4)
+            .expect("Failed to initialize FRI context");
+        let (packed_roots, aggregate) = friveil
+            .commit_roots(&roots, fri_params.clone(), &ntt)
+            .expect("Failed to commit roots");
+        let aggregate_root: [u8; 32] = aggregate.commitment.clone().try_into().unwrap();
+
+        let index = 5;
+        let prove = || {
+            friveil
+                .prove_root_inclusion(&packed_roots, fri_params.clone(), &ntt, &aggregate, index)
+                .expect("Failed to prove root inclusion")
+        };
+        assert!(
+            friveil
+                .verify_root_inclusion(
+                    &mut prove(),
+                    aggregate_root,
+                    index,
+                    &roots[index],
+                    &fri_params
+                )
+                .is_ok()
+        );
 
-        // Test small to large field conversion
-        let small_field_values: Vec<B1> = vec![B1::ZERO, B1::ONE, B1::ZERO, B1::ONE];
-        let large_field_values: Vec<B128> = friveil.lift_small_to_large_field(&small_field_values);
+        // Another blob's root, or the right root at another index, is rejected
+        assert!(
+            friveil
+                .verify_root_inclusion(&mut prove(), aggregate_root, index, &roots[4], &fri_params)
+                .is_err()
+        );
+        assert!(
+            friveil
+                .verify_root_inclusion(&mut prove(), aggregate_root, 4, &roots[index], &fri_params)
+                .is_err()
+        );
+        assert!(friveil.root_inclusion_point(&aggregate_root, 8).is_err());
+    }
 
-        assert_eq!(large_field_values.len(), small_field_values.len());
-        assert_eq!(large_field_values[0], B128::from(B1::ZERO));
-        assert_eq!(large_field_values[1], B128::from(B1::ONE));
+    #[test]
+    fn test_proof_metadata_reports_data_len() {
+        let fixture = ProofFixture::new(1000);
+        let binding = ProofBinding::new().with_data_len(1000);
+        let proof_bytes = fixture
+            .friveil
+            .get_transcript_bytes(&fixture.prove_bound(&binding));
+
+        let metadata = proof_metadata(&proof_bytes).expect("Failed to read proof metadata");
+        assert_eq!(metadata.data_len, Some(1000));
+        assert_eq!(metadata.commitment, fixture.root());
+        assert!(metadata.header.check_against(&fixture.fri_params).is_ok());
+
+        let unbound = fixture.friveil.get_transcript_bytes(&fixture.prove());
+        assert_eq!(proof_metadata(&unbound).unwrap().data_len, None);
+        assert!(proof_metadata(&unbound[..10]).is_err());
+
+        // A verifier expecting another blob size rejects the proof
+        let mut transcript = fixture.prove_bound(&binding);
+        let err = fixture
+            .friveil
+            .verify_evaluation_bound(
+                &mut transcript,
+                &ProofBinding::new().with_data_len(1024),
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+            .expect_err("Proof for 1000 bytes should fail for 1024");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::BindingMismatch {
+                    field: "data length",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
 
-        // Test large to small field conversion
-        let test_large_values: Vec<B128> = vec![B128::from(42u128), B128::from(100u128)];
-        let converted_small: Vec<B1> =
-            friveil.large_field_mle_to_small_field_mle(&test_large_values);
+    #[test]
+    fn test_records_to_packed_mle_opens_single_record() {
+        let utils = Utils::<B128>::new();
+        let records: Vec<[u8; 64]> = (0..8u8).map(|i| [i.wrapping_mul(29); 64]).collect();
+
+        // 64-byte records fill 4-element slots exactly, matching plain packing
+        let packed_mle = utils
+            .records_to_packed_mle(records.iter().copied())
+            .unwrap();
+        let direct = utils.bytes_to_packed_mle(records.as_flattened()).unwrap();
+        assert_eq!(packed_mle.packed_values, direct.packed_values);
+
+        // 40-byte records are padded to the same 4-element slots
+        let short: Vec<[u8; 40]> = (0..3u8).map(|i| [i + 1; 40]).collect();
+        let padded = utils.records_to_packed_mle(short.iter().copied()).unwrap();
+        assert_eq!(padded.packed_values.len(), 16);
+        assert_eq!(padded.packed_values[3], B128::zero());
+        assert_eq!(
+            padded.packed_values[4],
+            B128::from(u128::from_le_bytes([2; 16]))
+        );
 
-        // Each B128 should expand to 128 B1 elements
-        assert_eq!(converted_small.len(), test_large_values.len() * 128);
+        let friveil = FriVeilDefault::new(1, 3, packed_mle.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle.packed_mle.log_len())
+            .unwrap();
+        let commit_output = friveil
+            .commit(packed_mle.packed_mle.clone(), fri_params.clone(), &ntt)
+            .unwrap();
+        let root: [u8; 32] = commit_output.commitment.clone().try_into().unwrap();
+
+        let index = 6;
+        let prove = || {
+            friveil
+                .prove_record_inclusion(
+                    &packed_mle,
+                    fri_params.clone(),
+                    &ntt,
+                    &commit_output,
+                    index,
+                    64,
+                )
+                .expect("Failed to prove record inclusion")
+        };
+        assert!(
+            friveil
+                .verify_record_inclusion(&mut prove(), root, index, &records[index], &fri_params)
+                .is_ok()
+        );
+        assert!(
+            friveil
+                .verify_record_inclusion(&mut prove(), root, index, &records[5], &fri_params)
+                .is_err()
+        );
     }
 
     #[test]
-    fn test_initialize_fri_context() {
-        let friveil = TestFriVeil::new(1, 3, 12, 2);
-
-        // Create test data
-        let test_data = create_test_data(1024); // 1KB test data
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
+    fn test_bundle_round_trip_verifies() {
+        let fixture = ProofFixture::new(4096);
+        let bundle = fixture
+            .friveil
+            .bundle(
+                &fixture.commit_output,
+                &fixture.prove(),
+                &fixture.fri_params,
+            )
+            .expect("Failed to bundle");
 
-        let result = friveil.initialize_fri_context(packed_mle_values.packed_mle.log_len());
-        assert!(result.is_ok());
+        let (root, mut proof) = fixture
+            .friveil
+            .unbundle(&bundle, &fixture.fri_params)
+            .expect("Failed to unbundle");
+        assert_eq!(root.as_slice(), fixture.commit_output.commitment.as_slice());
+        assert!(
+            fixture
+                .friveil
+                .verify_evaluation_with_root(
+                    &mut proof,
+                    root,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &fixture.fri_params,
+                )
+                .is_ok()
+        );
 
-        let (fri_params, _ntt) = result.unwrap();
+        let err = fixture
+            .friveil
+            .unbundle(&bundle[..bundle.len() - 10], &fixture.fri_params)
+            .expect_err("Truncated bundle should be rejected");
+        assert!(
+            matches!(err, FriVeilError::TranscriptTruncated(_)),
+            "{}",
+            err
+        );
 
-        // Verify FRI parameters are reasonable
-        assert_eq!(fri_params.rs_code().log_inv_rate(), friveil.log_inv_rate);
-        assert_eq!(fri_params.n_test_queries(), friveil.num_test_queries);
+        // The bundle's 3 queries don't satisfy a verifier expecting 4
+        let (stricter_params, _) =
+            FriVeilDefault::new(1, 4, fixture.packed_mle_values.total_n_vars, 3)
+                .initialize_fri_context(fixture.packed_mle_values.packed_mle.log_len())
+                .expect("Failed to initialize FRI context");
+        let err = fixture
+            .friveil
+            .unbundle(&bundle, &stricter_params)
+            .expect_err("Bundle with fewer queries should be rejected");
+        assert_eq!(
+            err,
+            FriVeilError::HeaderMismatch {
+                field: "num_test_queries",
+                expected: 4,
+                got: 3,
+            }
+        );
     }
 
     #[test]
-    fn test_commit_and_inclusion_proofs() {
-        let friveil = TestFriVeil::new(1, 3, 12, 2);
+    fn test_packed_mle_cache_round_trip_commits_to_same_root() {
+        let fixture = ProofFixture::new(4096);
+        let bytes = fixture.packed_mle_values.to_bytes();
+        let cached = PackedMLE::<B128>::from_bytes(&bytes).expect("Failed to reload MLE");
+        assert_eq!(cached.total_n_vars, fixture.packed_mle_values.total_n_vars);
+        assert_eq!(
+            cached.packed_values,
+            fixture.packed_mle_values.packed_values
+        );
 
-        // Create test data
-        let test_data = create_test_data(1024);
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
+        let commit_output = fixture
+            .friveil
+            .commit(cached.packed_mle, fixture.fri_params.clone(), &fixture.ntt)
+            .expect("Failed to commit cached MLE");
+        assert_eq!(commit_output.commitment, fixture.commit_output.commitment);
+
+        assert!(PackedMLE::<B128>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(matches!(
+            PackedMLE::<B128>::from_bytes(&bytes[..1]),
+            Err(FriVeilError::FieldDecode(_))
+        ));
+
+        // 2^60 elements of 16 bytes overflow usize; this must not wrap to an empty body
+        assert!(matches!(
+            PackedMLE::<B128>::from_bytes(&[bytes[0], 67]),
+            Err(FriVeilError::InvalidLogLen(_))
+        ));
+    }
 
-        let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
-            .expect("Failed to initialize FRI context");
+    #[test]
+    fn test_nonced_roots_differ_and_verify_under_own_nonce() {
+        let fixture = ProofFixture::new(4096);
+        let nonces = [[1u8; 32], [2u8; 32]];
+        let roots = nonces.map(|nonce| fixture.commit_output.nonced_root(&nonce));
+        assert_ne!(roots[0], roots[1]);
+        assert_eq!(roots[0], fixture.commit_output.nonced_root(&nonces[0]));
+
+        let verify = |proof_nonce: &[u8; 32], nonce: &[u8; 32], root: [u8; 32]| {
+            let mut proof = fixture.prove_bound(&ProofBinding::new().with_nonce(*proof_nonce));
+            fixture.friveil.verify_evaluation_with_nonce(
+                &mut proof,
+                root,
+                nonce,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
+        for (nonce, root) in nonces.iter().zip(roots) {
+            assert!(verify(nonce, nonce, root).is_ok());
+        }
 
-        // Test commit
-        let commit_result = friveil.commit(
-            packed_mle_values.packed_mle.clone(),
-            fri_params.clone(),
-            &ntt,
+        let err = verify(&nonces[0], &nonces[1], roots[1]).expect_err("Wrong nonce should fail");
+        assert!(
+            matches!(err, FriVeilError::BindingMismatch { field: "nonce", .. }),
+            "{}",
+            err
         );
-        assert!(commit_result.is_ok());
+        let err = verify(&nonces[1], &nonces[1], roots[0]).expect_err("Wrong root should fail");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
+        );
+    }
 
-        let commit_output = commit_result.unwrap();
-        assert!(!commit_output.commitment.is_empty());
-        assert!(!commit_output.codeword.is_empty());
+    #[test]
+    fn test_commit_with_header_verifies_header_and_body() {
+        let fixture = ProofFixture::new(4096);
+        let friveil = &fixture.friveil;
+        let header = b"blob v1, 4096 bytes";
+        let (commit_output, combined_root) = friveil
+            .commit_with_header(
+                header,
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+            )
+            .expect("Failed to commit with header");
+        assert_eq!(commit_output.commitment, fixture.commit_output.commitment);
 
-        let commitment_bytes: [u8; 32] = commit_output
-            .commitment
-            .to_vec()
-            .try_into()
-            .expect("We know commitment size is 32 bytes");
-        // Test inclusion proofs for first few elements
-        for i in 0..std::cmp::min(5, commit_output.codeword.len()) {
-            let value = commit_output.codeword[i];
+        // Header path: hashes only
+        let header_proof = friveil.prove_header(&commit_output).unwrap();
+        assert!(
+            friveil
+                .verify_header(combined_root, header, &header_proof)
+                .is_ok()
+        );
+        let err = friveil
+            .verify_header(combined_root, b"blob v2, 4096 bytes", &header_proof)
+            .expect_err("Different header should fail");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
+        );
 
-            // Generate inclusion proof
-            let inclusion_proof_result = friveil.inclusion_proof(&commit_output.committed, i);
-            assert!(inclusion_proof_result.is_ok());
+        // Body path: evaluation proof tied to the combined root
+        let prove_body = || {
+            friveil
+                .prove_body(
+                    fixture.packed_mle_values.packed_mle.clone(),
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                    &commit_output,
+                    &fixture.evaluation_point,
+                )
+                .expect("Failed to prove body")
+        };
+        let verify_body = |header: &[u8]| {
+            friveil.verify_body(
+                &mut prove_body(),
+                combined_root,
+                &header_digest(header),
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
+        assert!(verify_body(header).is_ok());
+        let err = verify_body(b"other header").expect_err("Wrong header digest should fail");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
+        );
+    }
 
-            let mut inclusion_proof = inclusion_proof_result.unwrap();
+    #[test]
+    fn test_verify_proof_structure_ignores_commitment() {
+        let fixture = ProofFixture::new(4096);
+        let proof_bytes = transcript_bytes(&fixture.prove());
+        let mut other_root = fixture.root();
+        other_root[0] ^= 1;
+
+        // Well formed, but not a proof for another commitment
+        assert!(verify_proof_structure(&proof_bytes, &fixture.fri_params).is_ok());
+        let mut proof = fixture
+            .friveil
+            .reconstruct_transcript_from_bytes(proof_bytes.clone());
+        assert!(
+            fixture
+                .friveil
+                .verify_evaluation_with_root(
+                    &mut proof,
+                    other_root,
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &fixture.fri_params,
+                )
+                .is_err()
+        );
 
-            // Verify inclusion proof
-            let verify_result = friveil.verify_inclusion_proof(
-                &mut inclusion_proof,
-                &[value],
-                i,
-                &fri_params,
-                commitment_bytes,
-            );
-            assert!(
-                verify_result.is_ok(),
-                "Inclusion proof verification failed for index {}",
-                i
-            );
-        }
+        let prefix_len = ProofHeader::ENCODED_LEN + 2 + 32 + 32;
+        assert!(verify_proof_structure(&proof_bytes[..prefix_len], &fixture.fri_params).is_err());
+        assert!(verify_proof_structure(&proof_bytes[..20], &fixture.fri_params).is_err());
+
+        // A valid prefix with a one-byte body cannot answer the queries
+        let one_byte_body = proof_bytes[..prefix_len + 1].to_vec();
+        assert!(matches!(
+            verify_proof_structure(&one_byte_body, &fixture.fri_params),
+            Err(FriVeilError::InvalidInput(_))
+        ));
+
+        // Padding past the bound for the parameters is rejected
+        let max = fixture.friveil.max_proof_bytes(&fixture.fri_params);
+        assert!(proof_bytes.len() >= fixture.friveil.min_proof_bytes(&fixture.fri_params));
+        let mut padded = proof_bytes.clone();
+        padded.resize(max + 1, 0);
+        assert!(matches!(
+            verify_proof_structure(&padded, &fixture.fri_params),
+            Err(FriVeilError::InvalidInput(_))
+        ));
     }
 
     #[test]
-    fn test_calculate_evaluation_claim() {
-        let test_data = create_test_data(1024 * 1024); // 1mb test data
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
+    fn test_audit_record_replays_independently() {
+        let fixture = ProofFixture::new(4096);
+        let record = fixture
+            .friveil
+            .audit_record(
+                &create_test_data(4096),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+                &fixture.commit_output,
+                &fixture.evaluation_point,
+            )
+            .expect("Failed to build audit record");
+
+        // The record and the auditor's own parameters are enough to re-verify
+        assert!(record.replay(&fixture.fri_params).is_ok());
+        assert!(record.check_data(&create_test_data(4096)).is_ok());
+        assert!(record.check_data(&create_test_data(4095)).is_err());
+
+        let mut tampered = record.clone();
+        tampered.evaluation_claim ^= 1;
+        let err = tampered
+            .replay(&fixture.fri_params)
+            .expect_err("Tampered record should fail");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
+        );
+        tampered.record_digest = tampered.compute_digest();
+        assert!(tampered.replay(&fixture.fri_params).is_err());
 
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+        // A record re-digested with a lower query count doesn't lower the auditor's
+        let mut weakened = record;
+        weakened.num_test_queries = 1;
+        weakened.record_digest = weakened.compute_digest();
+        assert_eq!(
+            weakened.replay(&fixture.fri_params),
+            Err(FriVeilError::HeaderMismatch {
+                field: "num_test_queries",
+                expected: 3,
+                got: 1,
+            })
+        );
+    }
 
-        let evaluation_point = friveil
-            .calculate_evaluation_point_random()
-            .expect("Failed to generate evaluation point");
+    #[test]
+    fn test_evaluation_point_from_commitment() {
+        let fixture = ProofFixture::new(4096);
+        let friveil = &fixture.friveil;
+        let evaluation_point =
+            friveil.calculate_evaluation_point_from_commitment(&fixture.commit_output);
+        assert_eq!(
+            evaluation_point.len(),
+            fixture.packed_mle_values.total_n_vars
+        );
+        assert_eq!(
+            evaluation_point,
+            friveil.evaluation_point_from_root(&fixture.root())
+        );
+        let mut other_root = fixture.root();
+        other_root[0] ^= 1;
+        assert_ne!(
+            evaluation_point,
+            friveil.evaluation_point_from_root(&other_root)
+        );
 
-        let result =
-            friveil.calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point);
-        assert!(result.is_ok());
+        let evaluation_claim = friveil
+            .calculate_evaluation_claim(&fixture.packed_mle_values.packed_values, &evaluation_point)
+            .unwrap();
+        let prove = || {
+            friveil
+                .prove_at_commitment_point(
+                    fixture.packed_mle_values.packed_mle.clone(),
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                    &fixture.commit_output,
+                )
+                .expect("Failed to prove at commitment point")
+        };
+        assert!(
+            friveil
+                .verify_evaluation_at_commitment_point(
+                    &mut prove(),
+                    fixture.root(),
+                    evaluation_claim,
+                    &fixture.fri_params,
+                )
+                .is_ok()
+        );
 
-        let evaluation_claim = result.unwrap();
-        // The evaluation claim should be a valid field element
-        assert_ne!(evaluation_claim, B128::default()); // Should not be zero for random inputs
+        // A proof at an externally chosen point is rejected
+        let err = friveil
+            .verify_evaluation_at_commitment_point(
+                &mut fixture.prove(),
+                fixture.root(),
+                fixture.evaluation_claim,
+                &fixture.fri_params,
+            )
+            .expect_err("Proof at another point should fail");
+        assert_eq!(err, FriVeilError::PointMismatch);
     }
 
     #[test]
-    fn test_full_prove_verify_workflow() {
-        // Create test data
-        let test_data = create_test_data(1024 * 1024); // 2KB test data
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
+    fn test_cloned_friveil_proves_concurrently() {
+        let fixture = ProofFixture::new(4096);
+        let clone = fixture.friveil.clone();
+        assert!(Arc::ptr_eq(
+            &clone.merkle_prover,
+            &fixture.friveil.merkle_prover
+        ));
+
+        let commit_output = clone
+            .commit(
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+            )
+            .unwrap();
+        assert_eq!(commit_output.commitment, fixture.commit_output.commitment);
+
+        let fixture = &fixture;
+        let proofs: Vec<Vec<u8>> = std::thread::scope(|scope| {
+            [&fixture.friveil, &clone]
+                .map(|friveil| {
+                    scope.spawn(move || {
+                        friveil
+                            .prove_to_bytes(
+                                fixture.packed_mle_values.packed_mle.clone(),
+                                fixture.fri_params.clone(),
+                                &fixture.ntt,
+                                &fixture.commit_output,
+                                &fixture.evaluation_point,
+                            )
+                            .expect("Failed to prove")
+                    })
+                })
+                .map(|handle| handle.join().unwrap())
+                .to_vec()
+        });
+        assert_eq!(proofs[0], proofs[1]);
+        let mut proof = clone.reconstruct_transcript_from_bytes(proofs[1].clone());
+        assert!(
+            clone
+                .verify_evaluation_with_root(
+                    &mut proof,
+                    fixture.root(),
+                    fixture.evaluation_claim,
+                    &fixture.evaluation_point,
+                    &fixture.fri_params,
+                )
+                .is_ok()
+        );
+    }
 
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
-        // Initialize FRI context
-        let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
-            .expect("Failed to initialize FRI context");
+    #[test]
+    fn test_commit_from_codeword_matches_commit() {
+        let fixture = ProofFixture::new(4096);
+        let codeword = fixture
+            .friveil
+            .encode_codeword(
+                &fixture.packed_mle_values.packed_values,
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+            )
+            .expect("Failed to encode codeword");
 
-        // Generate evaluation point
-        let evaluation_point = friveil
-            .calculate_evaluation_point_random()
-            .expect("Failed to generate evaluation point");
+        let commit_output = fixture
+            .friveil
+            .commit_from_codeword(&codeword, fixture.fri_params.clone(), &fixture.ntt)
+            .expect("Failed to commit to codeword");
+        assert_eq!(commit_output.commitment, fixture.commit_output.commitment);
 
-        // Commit to MLE
-        let commit_output = friveil
-            .commit(
-                packed_mle_values.packed_mle.clone(),
-                fri_params.clone(),
-                &ntt,
+        let mut corrupted = codeword.clone();
+        corrupted[3] += B128::ONE;
+        assert!(
+            fixture
+                .friveil
+                .commit_from_codeword(&corrupted, fixture.fri_params.clone(), &fixture.ntt)
+                .is_err()
+        );
+        assert!(
+            fixture
+                .friveil
+                .commit_from_codeword(&codeword[1..], fixture.fri_params.clone(), &fixture.ntt)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_explain_failure_locates_divergence() {
+        let fixture = ProofFixture::new(4096);
+        let proof_bytes = transcript_bytes(&fixture.prove());
+        let explain = |bytes: &[u8], evaluation_point: &[B128]| {
+            fixture.friveil.explain_failure(
+                &mut fixture
+                    .friveil
+                    .reconstruct_transcript_from_bytes(bytes.to_vec()),
+                ExpectedCommitment::Root(fixture.root()),
+                &ProofBinding::new(),
+                fixture.evaluation_claim,
+                evaluation_point,
+                &fixture.fri_params,
             )
-            .expect("Failed to commit");
+        };
 
-        // Generate proof
-        let prove_result = friveil.prove(
-            packed_mle_values.packed_mle.clone(),
-            fri_params.clone(),
-            &ntt,
-            &commit_output,
-            &evaluation_point,
+        assert_eq!(
+            explain(&proof_bytes, &fixture.evaluation_point),
+            FailureExplanation::Verified
         );
-        assert!(prove_result.is_ok());
 
-        let mut verifier_transcript = prove_result.unwrap();
+        let mut tampered = proof_bytes.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        let explanation = explain(&tampered, &fixture.evaluation_point);
+        assert!(
+            matches!(
+                &explanation,
+                FailureExplanation::Pcs { commitment, .. } if *commitment == fixture.root()
+            ),
+            "Unexpected explanation: {}",
+            explanation
+        );
 
-        // Calculate evaluation claim
-        let evaluation_claim = friveil
-            .calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point)
-            .expect("Failed to calculate evaluation claim");
+        let mut other_point = fixture.evaluation_point.clone();
+        other_point[0] += B128::ONE;
+        assert!(matches!(
+            explain(&proof_bytes, &other_point),
+            FailureExplanation::EvaluationPoint { .. }
+        ));
+
+        let mut wrong_version = proof_bytes;
+        wrong_version[0] ^= 1;
+        assert!(matches!(
+            explain(&wrong_version, &fixture.evaluation_point),
+            FailureExplanation::Header { .. }
+        ));
+    }
 
-        // Verify proof
-        let verify_result = friveil.verify_evaluation(
-            &mut verifier_transcript,
-            evaluation_claim,
-            &evaluation_point,
-            &fri_params,
-        );
-        assert!(
-            verify_result.is_ok(),
-            "Verification failed: {:?}",
-            verify_result
+    #[test]
+    fn test_explain_failure_uses_expected_binding_and_commitment() {
+        let fixture = ProofFixture::new(4096);
+        let binding = ProofBinding::new().with_epoch(5);
+        let proof_bytes = transcript_bytes(&fixture.prove_bound(&binding));
+        let explain = |expected: ExpectedCommitment<'_>, expected_binding: &ProofBinding| {
+            fixture.friveil.explain_failure(
+                &mut fixture
+                    .friveil
+                    .reconstruct_transcript_from_bytes(proof_bytes.clone()),
+                expected,
+                expected_binding,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
+
+        // A valid bound proof is explained as verified, not as a binding failure
+        assert_eq!(
+            explain(ExpectedCommitment::Root(fixture.root()), &binding),
+            FailureExplanation::Verified
         );
+        assert!(matches!(
+            explain(
+                ExpectedCommitment::Root(fixture.root()),
+                &ProofBinding::new()
+            ),
+            FailureExplanation::Binding { .. }
+        ));
+        assert!(matches!(
+            explain(ExpectedCommitment::Root([0; 32]), &binding),
+            FailureExplanation::Commitment { .. }
+        ));
     }
 
     #[test]
-    fn test_invalid_verification_fails() {
-        // Create test data
-        let test_data = create_test_data(512);
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+    fn test_file_to_packed_mle_matches_in_memory() {
+        let utils = Utils::<B128>::new();
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("friveil-file-mle-{}", std::process::id()));
+        let data = create_test_data(4096 + 7);
+        std::fs::write(&path, &data).unwrap();
+
+        let from_file = utils
+            .file_to_packed_mle(&path)
+            .expect("Failed to convert file");
+        let in_memory = utils.bytes_to_packed_mle(&data).unwrap();
+        assert_eq!(from_file.packed_values, in_memory.packed_values);
+        assert_eq!(from_file.total_n_vars, in_memory.total_n_vars);
+
+        let friveil = TestFriVeil::new(1, 3, from_file.total_n_vars, 3);
         let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
-            .expect("Failed to initialize FRI context");
-
-        let commit_output = friveil
-            .commit(
-                packed_mle_values.packed_mle.clone(),
-                fri_params.clone(),
-                &ntt,
-            )
-            .expect("Failed to commit");
-
-        let evaluation_point = friveil
-            .calculate_evaluation_point_random()
-            .expect("Failed to generate evaluation point");
-
-        let mut verifier_transcript = friveil
-            .prove(
-                packed_mle_values.packed_mle.clone(),
-                fri_params.clone(),
-                &ntt,
-                &commit_output,
-                &evaluation_point,
-            )
-            .expect("Failed to generate proof");
+            .initialize_fri_context(from_file.packed_mle.log_len())
+            .unwrap();
+        let commit = |packed_mle: PackedMLE<B128>| {
+            friveil
+                .commit(packed_mle.packed_mle, fri_params.clone(), &ntt)
+                .unwrap()
+                .commitment
+        };
+        assert_eq!(commit(from_file), commit(in_memory));
 
-        // Use wrong evaluation claim (should cause verification to fail)
-        let wrong_evaluation_claim = B128::from(42u128);
+        std::fs::write(&path, b"").unwrap();
+        assert_eq!(
+            utils.file_to_packed_mle(&path).err(),
+            Some(FriVeilError::EmptyInput)
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(utils.file_to_packed_mle(&path).is_err());
+    }
 
-        let verify_result = friveil.verify_evaluation(
-            &mut verifier_transcript,
-            wrong_evaluation_claim,
-            &evaluation_point,
-            &fri_params,
+    #[test]
+    fn test_proofs_are_byte_identical_across_runs() {
+        let fixture = ProofFixture::new(4096);
+        let proof = fixture.friveil.get_transcript_bytes(&fixture.prove());
+        assert_eq!(
+            proof,
+            fixture.friveil.get_transcript_bytes(&fixture.prove())
         );
 
-        // Verification should fail with wrong claim
-        assert!(
-            verify_result.is_err(),
-            "Verification should fail with wrong evaluation claim"
+        // A fresh commitment and proof of the same input reproduce the same bytes
+        let rerun = ProofFixture::new(4096);
+        assert_eq!(
+            rerun.commit_output.commitment,
+            fixture.commit_output.commitment
         );
+        assert_eq!(proof, rerun.friveil.get_transcript_bytes(&rerun.prove()));
+
+        #[cfg(feature = "parallel")]
+        {
+            let single_threaded = rayon::ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .unwrap()
+                .install(|| {
+                    let fixture = ProofFixture::new(4096);
+                    fixture.friveil.get_transcript_bytes(&fixture.prove())
+                });
+            assert_eq!(single_threaded, proof);
+        }
     }
 
     #[test]
-    fn test_data_availability_sampling() {
-        use rand::{SeedableRng, rngs::StdRng, seq::index::sample};
-        use tracing::Level;
+    fn test_commit_batch_matches_individual_commits() {
+        let fixture = ProofFixture::new(4096);
+        let utils = Utils::<B128>::new();
+        let total_n_vars = fixture.packed_mle_values.total_n_vars;
+        let mles: Vec<PackedMLE<B128>> = (0..3)
+            .map(|seed| {
+                utils
+                    .random_packed_mle(total_n_vars, seed)
+                    .expect("Failed to create packed MLE")
+            })
+            .collect();
 
-        // Initialize logging for the test
-        let _ = tracing_subscriber::fmt()
-            .with_max_level(Level::DEBUG)
-            .with_test_writer()
-            .try_init();
+        let commit_outputs = fixture
+            .friveil
+            .commit_batch(&mles, &fixture.fri_params, &fixture.ntt)
+            .expect("Failed to batch commit");
+        assert_eq!(commit_outputs.len(), mles.len());
+        for (mle, commit_output) in mles.iter().zip(&commit_outputs) {
+            let single = fixture
+                .friveil
+                .commit(
+                    mle.packed_mle.clone(),
+                    fixture.fri_params.clone(),
+                    &fixture.ntt,
+                )
+                .expect("Failed to commit");
+            assert_eq!(commit_output.commitment, single.commitment);
+        }
 
-        // Create test data
-        let test_data = create_test_data(1024 * 1024); // 1MB test data
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
+        let mut mixed = mles;
+        mixed.push(
+            utils
+                .random_packed_mle(total_n_vars + 1, 3)
+                .expect("Failed to create packed MLE"),
+        );
+        let err = fixture
+            .friveil
+            .commit_batch(&mixed, &fixture.fri_params, &fixture.ntt)
+            .expect_err("Mis-sized MLE should fail the batch");
+        assert!(matches!(err, FriVeilError::InvalidLogLen(_)), "{}", err);
+    }
 
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+    #[test]
+    fn test_fri_context_cached_reuses_ntt() {
+        let fixture = ProofFixture::new(4096);
+        let log_len = fixture.packed_mle_values.packed_mle.log_len();
 
-        // Initialize FRI context
-        let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+        let (fri_params, ntt) = fixture
+            .friveil
+            .fri_context_cached(log_len)
+            .expect("Failed to initialize FRI context");
+        let (_, second) = fixture
+            .friveil
+            .fri_context_cached(log_len)
+            .expect("Failed to initialize FRI context");
+        assert!(Arc::ptr_eq(&ntt, &second));
+        let (_, cloned) = fixture
+            .friveil
+            .clone()
+            .fri_context_cached(log_len)
+            .expect("Failed to initialize FRI context");
+        assert!(Arc::ptr_eq(&ntt, &cloned));
+        let (_, other) = fixture
+            .friveil
+            .fri_context_cached(log_len + 1)
             .expect("Failed to initialize FRI context");
+        assert!(!Arc::ptr_eq(&ntt, &other));
 
-        // Commit to MLE
-        let commit_output = friveil
+        let commit_output = fixture
+            .friveil
             .commit(
-                packed_mle_values.packed_mle.clone(),
-                fri_params.clone(),
+                fixture.packed_mle_values.packed_mle.clone(),
+                fri_params,
                 &ntt,
             )
             .expect("Failed to commit");
+        assert_eq!(commit_output.commitment, fixture.commit_output.commitment);
+    }
 
-        let total_samples = commit_output.codeword.len();
-        let sample_size = total_samples / 2;
-        let indices =
-            sample(&mut StdRng::from_seed([0; 32]), total_samples, sample_size).into_vec();
-        let commitment_bytes: [u8; 32] = commit_output
-            .commitment
-            .to_vec()
-            .try_into()
-            .expect("We know commitment size is 32 bytes");
-
-        let mut successful_samples = 0;
-        let mut failed_samples = Vec::new();
+    #[test]
+    fn test_fri_context_cached_recovers_from_poisoned_lock() {
+        let fixture = ProofFixture::new(4096);
+        let log_len = fixture.packed_mle_values.packed_mle.log_len();
+        let (_, ntt) = fixture
+            .friveil
+            .fri_context_cached(log_len)
+            .expect("Failed to initialize FRI context");
 
-        for &sample_index in indices.iter() {
-            match friveil.inclusion_proof(&commit_output.committed, sample_index) {
-                Ok(mut inclusion_proof) => {
-                    let value = commit_output.codeword[sample_index];
-                    match friveil.verify_inclusion_proof(
-                        &mut inclusion_proof,
-                        &[value],
-                        sample_index,
-                        &fri_params,
-                        commitment_bytes,
-                    ) {
-                        Ok(_) => {
-                            successful_samples += 1;
-                        }
-                        Err(e) => {
-                            failed_samples
-                                .push((sample_index, format!("Verification failed: {}", e)));
-                        }
-                    }
-                }
-                Err(e) => {
-                    failed_samples.push((
-                        sample_index,
-                        format!("Inclusion proof generation failed: {}", e),
-                    ));
-                }
-            }
-        }
+        // A panic while the lock is held poisons it for every clone sharing the cache
+        let clone = fixture.friveil.clone();
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = clone.ntt_cache.write().unwrap();
+            panic!("worker panicked while holding the NTT cache");
+        }));
+        assert!(fixture.friveil.ntt_cache.is_poisoned());
+
+        let (_, cached) = fixture
+            .friveil
+            .fri_context_cached(log_len)
+            .expect("Poisoned cache should still be readable");
+        assert!(Arc::ptr_eq(&ntt, &cached));
+        assert!(fixture.friveil.fri_context_cached(log_len + 1).is_ok());
+    }
 
-        assert_eq!(failed_samples.len(), 0, "Some samples failed verification");
-        assert_eq!(
-            successful_samples, sample_size,
-            "Not all samples were verified"
+    #[test]
+    fn test_oversized_proof_rejected_before_buffering() {
+        let fixture = ProofFixture::new(4096);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let max_proof_bytes = friveil.max_proof_bytes(&fixture.fri_params);
+        let proof_bytes = friveil.get_transcript_bytes(&fixture.prove());
+        assert!(
+            proof_bytes.len() <= max_proof_bytes,
+            "Honest proof of {} bytes exceeds the bound {}",
+            proof_bytes.len(),
+            max_proof_bytes
         );
 
-        println!("Successfully verified {} samples", successful_samples);
+        let mut transcript = friveil
+            .reconstruct_transcript_bounded(&proof_bytes, max_proof_bytes)
+            .expect("Honest proof should fit the bound");
+        let result = friveil.verify_evaluation(
+            &mut transcript,
+            fixture.evaluation_claim,
+            &fixture.evaluation_point,
+            &fixture.fri_params,
+        );
+        assert!(result.is_ok(), "Verification failed: {:?}", result);
+
+        let mut padded = proof_bytes.clone();
+        padded.resize(max_proof_bytes + 1, 0);
+        let err = friveil
+            .reconstruct_transcript_bounded(&padded, max_proof_bytes)
+            .expect_err("Over-padded proof should be rejected");
+        assert!(matches!(err, FriVeilError::InvalidInput(_)), "{}", err);
+
+        // An endless stream behind a valid header is cut off at the bound
+        let endless = std::io::Read::chain(proof_bytes.as_slice(), std::io::repeat(0));
+        let err = friveil
+            .reconstruct_transcript_from_reader(endless, &fixture.fri_params)
+            .expect_err("Endless stream should be rejected");
+        assert!(matches!(err, FriVeilError::InvalidInput(_)), "{}", err);
     }
 
     #[test]
-    fn test_codeword_decode() {
-        // Create test data
-        let test_data = create_test_data(512);
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
-
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+    fn test_expired_proof_rejected() {
+        let fixture = ProofFixture::new(512);
+        let binding = ProofBinding::new().with_valid_until(1_000);
+        let verify = |binding: &ProofBinding, now: u64| {
+            let mut transcript = fixture.prove_bound(&ProofBinding::new().with_valid_until(1_000));
+            fixture.friveil.verify_evaluation_fresh(
+                &mut transcript,
+                binding,
+                now,
+                fixture.evaluation_claim,
+                &fixture.evaluation_point,
+                &fixture.fri_params,
+            )
+        };
 
-        // Initialize FRI context
-        let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
-            .expect("Failed to initialize FRI context");
+        assert!(verify(&binding, 999).is_ok());
+        assert!(verify(&binding, 1_000).is_ok());
+        let err = verify(&binding, 1_001).expect_err("Proof should be expired");
+        assert_eq!(
+            err,
+            FriVeilError::Expired {
+                valid_until: 1_000,
+                now: 1_001
+            }
+        );
 
-        // Encode codeword
-        let encoded_codeword = friveil
-            .encode_codeword(&packed_mle_values.packed_values, fri_params.clone(), &ntt)
-            .expect("Failed to encode codeword");
+        // Claiming a later expiry than the proof was bound to doesn't extend it
+        let err = verify(&ProofBinding::new().with_valid_until(2_000), 1_001)
+            .expect_err("Proof bound to another expiry should fail");
+        assert!(
+            matches!(
+                err,
+                FriVeilError::BindingMismatch {
+                    field: "expiry",
+                    ..
+                }
+            ),
+            "{}",
+            err
+        );
+    }
 
-        // Decode codeword
-        let decoded_codeword = friveil
-            .decode_codeword(&encoded_codeword, fri_params.clone(), &ntt)
-            .expect("Failed to decode codeword");
+    #[test]
+    fn test_calculate_evaluation_point_from_seed() {
+        let friveil = TestFriVeil::new(1, 3, 8, 2);
 
-        // Verify decoded codeword matches original values
+        let point = friveil
+            .calculate_evaluation_point_from_seed([7; 32])
+            .expect("Failed to generate evaluation point");
+        assert_eq!(point.len(), 8);
         assert_eq!(
-            decoded_codeword, packed_mle_values.packed_values,
-            "Decoded codeword should match original packed values"
+            point,
+            friveil
+                .calculate_evaluation_point_from_seed([7; 32])
+                .unwrap()
+        );
+        assert_ne!(
+            point,
+            friveil
+                .calculate_evaluation_point_from_seed([8; 32])
+                .unwrap()
         );
 
-        println!("✅ Codeword decode test passed");
+        // The expansion is pinned: first coordinate of the point for the zero seed
+        let digest = Sha256::new()
+            .chain_update(b"friveil-point-expansion")
+            .chain_update([0; 32])
+            .chain_update(0u64.to_le_bytes())
+            .finalize();
+        assert_eq!(
+            friveil
+                .calculate_evaluation_point_from_seed([0; 32])
+                .unwrap()[0],
+            B128::from(u128::from_le_bytes(digest[..16].try_into().unwrap()))
+        );
     }
 
     #[test]
-    fn test_error_correction_reconstruction() {
-        use rand::{SeedableRng, rngs::StdRng, seq::index::sample};
-
-        // Create test data
-        let test_data = create_test_data(2048);
-        let packed_mle_values = Utils::<B128>::new()
-            .bytes_to_packed_mle(&test_data)
-            .expect("Failed to create packed MLE");
-
-        let friveil = TestFriVeil::new(1, 3, packed_mle_values.total_n_vars, 3);
+    fn test_packed_mle_to_bytes_round_trip() {
+        use rand::{Rng, RngCore};
+
+        let utils = Utils::<B128>::new();
+        let mut rng = StdRng::seed_from_u64(509);
+        let lengths = [1, 15, 16, 17, 100, 1000, 1024, 4097];
+        let random_lengths: Vec<usize> = (0..8).map(|_| rng.random_range(1..5000)).collect();
+        for len in lengths.into_iter().chain(random_lengths) {
+            let mut data = vec![0u8; len];
+            rng.fill_bytes(&mut data);
+            // Trailing zeros must survive the padding being stripped
+            data[len - 1] = 0;
+
+            let packed_mle = utils
+                .bytes_to_packed_mle(&data)
+                .expect("Failed to create packed MLE");
+            assert_eq!(
+                utils.packed_mle_to_bytes(&packed_mle, len).unwrap(),
+                data,
+                "Round trip failed for {} bytes",
+                len
+            );
+        }
 
-        // Initialize FRI context
-        let (fri_params, ntt) = friveil
-            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
-            .expect("Failed to initialize FRI context");
+        let packed_mle = utils.bytes_to_packed_mle(&[1, 2, 3]).unwrap();
+        assert!(utils.packed_mle_to_bytes(&packed_mle, 17).is_err());
+        let err = utils
+            .packed_mle_to_bytes(&packed_mle, 2)
+            .expect_err("Dropping a nonzero byte should be rejected");
+        assert!(matches!(err, FriVeilError::InvalidInput(_)), "{}", err);
+    }
 
-        // Encode codeword
-        let encoded_codeword = friveil
-            .encode_codeword(&packed_mle_values.packed_values, fri_params.clone(), &ntt)
-            .expect("Failed to encode codeword");
+    #[test]
+    fn test_bytes_to_packed_mle_typed_errors() {
+        let utils = Utils::<B128>::new();
+        assert_eq!(
+            utils.bytes_to_packed_mle(&[]).err(),
+            Some(FriVeilError::EmptyInput)
+        );
 
-        // Corrupt the codeword
-        let mut corrupted_codeword = encoded_codeword.clone();
-        let total_elements = corrupted_codeword.len();
-        let corruption_percentage = 0.1;
+        // No slice can reach max_data_bytes, so check the size gate bytes_to_packed_mle
+        // runs before converting anything
+        let max = utils.max_data_bytes();
+        let err = utils
+            .packed_log_len(usize::MAX)
+            .expect_err("Oversized data should be rejected");
+        assert_eq!(
+            err,
+            FriVeilError::DataTooLarge {
+                got: usize::MAX,
+                max
+            }
+        );
+    }
 
-        // Corrupt random elements
-        let num_corrupted = (total_elements as f64 * corruption_percentage) as usize;
-        let mut rng = StdRng::seed_from_u64(42);
-        let corrupted_indices = sample(&mut rng, total_elements, num_corrupted).into_vec();
+    #[test]
+    fn test_prove_from_store_matches_in_hand_commitment() {
+        use crate::store::InMemoryCommitmentStore;
 
-        for &index in &corrupted_indices {
-            corrupted_codeword[index] = B128::zero();
-        }
+        let fixture = ProofFixture::new(4096);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let commit_output = friveil
+            .commit(
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+            )
+            .expect("Failed to commit");
+        let in_hand = friveil
+            .prove_to_bytes(
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+                &commit_output,
+                &fixture.evaluation_point,
+            )
+            .expect("Failed to prove");
+
+        let root = commit_output.commitment.clone();
+        let mut store = InMemoryCommitmentStore::new();
+        store.insert(commit_output);
+        assert_eq!(store.len(), 1);
+
+        let prove = |root: &[u8]| {
+            friveil.prove_from_store(
+                &store,
+                root,
+                fixture.packed_mle_values.packed_mle.clone(),
+                fixture.fri_params.clone(),
+                &fixture.ntt,
+                &fixture.evaluation_point,
+            )
+        };
+        let from_store = prove(&root).expect("Failed to prove from store");
+        assert_eq!(friveil.get_transcript_bytes(&from_store), in_hand);
 
-        // Verify corruption happened
-        assert_ne!(
-            corrupted_codeword, encoded_codeword,
-            "Codeword should be corrupted"
+        let err = prove(&[0; 32]).expect_err("Unknown root should be rejected");
+        assert!(
+            matches!(err, FriVeilError::CommitmentMismatch(_)),
+            "{}",
+            err
         );
+    }
 
-        // Reconstruct corrupted codeword
-        friveil
-            .reconstruct_codeword_naive(&mut corrupted_codeword, &corrupted_indices)
-            .expect("Failed to reconstruct codeword");
-
-        // Verify reconstruction succeeded
+    #[test]
+    fn test_verify_evaluation_raw_rejects_malformed_point() {
+        let fixture = ProofFixture::new(512);
+        let friveil = FriVeilDefault::new(1, 3, fixture.packed_mle_values.total_n_vars, 3);
+        let claim = u128::from(fixture.evaluation_claim).to_le_bytes();
+        let point_bytes = encode_point(&fixture.evaluation_point);
         assert_eq!(
-            corrupted_codeword, encoded_codeword,
-            "Reconstructed codeword should match original encoded codeword"
+            decode_evaluation_point(&point_bytes).unwrap(),
+            fixture.evaluation_point
         );
 
-        // Decode the reconstructed codeword to verify it's correct
-        let decoded_reconstructed = friveil
-            .decode_codeword(&corrupted_codeword, fri_params.clone(), &ntt)
-            .expect("Failed to decode reconstructed codeword");
+        let mut transcript = fixture.prove();
+        let result = friveil.verify_evaluation_raw(
+            &mut transcript,
+            claim,
+            &point_bytes,
+            &fixture.fri_params,
+        );
+        assert!(result.is_ok(), "Verification failed: {:?}", result);
 
-        // Verify decoded reconstructed codeword matches original values
+        // The all-ones coordinate is the largest encoding and still a field element
         assert_eq!(
-            decoded_reconstructed, packed_mle_values.packed_values,
-            "Decoded reconstructed codeword should match original packed values"
+            decode_evaluation_point(&[0xff; 16]).unwrap(),
+            vec![B128::from(u128::MAX)]
         );
 
-        println!(
-            "✅ Error correction reconstruction test passed: {} elements, {:.1}% corruption",
-            total_elements,
-            corruption_percentage * 100.0
-        );
+        // A trailing partial coordinate is rejected, not dropped
+        let mut misframed = point_bytes.clone();
+        misframed.push(0xff);
+        let err = friveil
+            .verify_evaluation_raw(&mut fixture.prove(), claim, &misframed, &fixture.fri_params)
+            .expect_err("Misframed point should be rejected");
+        assert!(matches!(err, FriVeilError::FieldDecode(_)), "{}", err);
+
+        // An extra whole coordinate decodes but fails the length check
+        let mut extended = point_bytes;
+        extended.extend_from_slice(&[0xff; 16]);
+        let err = friveil
+            .verify_evaluation_raw(&mut fixture.prove(), claim, &extended, &fixture.fri_params)
+            .expect_err("Point with an extra coordinate should be rejected");
+        assert!(matches!(err, FriVeilError::ParameterMismatch(_)), "{}", err);
+    }
+
+    #[test]
+    fn test_commitment_root_round_trip() {
+        let fixture = ProofFixture::new(512);
+        let root = fixture
+            .commit_output
+            .root()
+            .expect("Root should be 32 bytes");
+        assert_eq!(root.as_slice(), fixture.commit_output.commitment.as_slice());
+
+        let commitment = Commitment::from_bytes(&fixture.commit_output.commitment)
+            .expect("Root should be a commitment");
+        assert_eq!(commitment.as_bytes(), &root);
+        assert_eq!(Commitment::from(root), commitment);
+        assert_eq!(<[u8; 32]>::from(commitment), root);
+
+        let err = Commitment::from_bytes(&root[..31]).expect_err("Short root should fail");
+        assert!(matches!(err, FriVeilError::InvalidInput(_)), "{}", err);
+
+        // A hand-edited output with a short root is an error, not a panic
+        let mut fixture = fixture;
+        fixture.commit_output.commitment.truncate(31);
+        assert!(matches!(
+            fixture.commit_output.root(),
+            Err(FriVeilError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            fixture.commit_output.prune(),
+            Err(FriVeilError::InvalidInput(_))
+        ));
     }
 }
@@ -0,0 +1,241 @@
+//! Proof header prepended to every evaluation proof
+//!
+//! `prove` writes the header into the transcript ahead of the
+//! [`ProofBinding`](crate::binding::ProofBinding), the
+//! [`point_digest`](crate::friveil::point_digest) and the commitment root, so it is
+//! both part of the serialized proof and absorbed into Fiat-Shamir. The verifier reads
+//! it first and compares it against its own parameters, turning prover/verifier
+//! configuration drift into a clear error instead of a failure deep inside FRI.
+//!
+//! # Layout
+//!
+//! ```text
+//! | version: u8 | field_id: u8 | log_inv_rate: u8 | num_test_queries: u32 LE |
+//! ```
+
+use crate::error::FriVeilError;
+use binius_field::ExtensionField;
+use binius_verifier::{
+    config::{B1, B128},
+    fri::FRIParams,
+};
+
+/// Proof format version written as the first header byte
+pub const PROOF_FORMAT_VERSION: u8 = 5;
+
+/// Identifier of the field a proof is over: log2 of its bit width (7 for B128)
+#[must_use]
+pub fn field_id<F: ExtensionField<B1>>() -> u8 {
+    F::LOG_DEGREE as u8
+}
+
+/// Parameters a proof was generated with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofHeader {
+    /// Proof format version, always [`PROOF_FORMAT_VERSION`] for headers this crate writes
+    pub version: u8,
+    /// Field the proof is over, see [`field_id`]
+    pub field_id: u8,
+    /// Logarithm of the Reed-Solomon inverse rate the codeword was encoded with
+    pub log_inv_rate: u8,
+    /// Number of FRI test queries answered by the proof
+    pub num_test_queries: u32,
+}
+
+impl ProofHeader {
+    /// Size of the encoded header in bytes
+    pub const ENCODED_LEN: usize = 7;
+
+    /// Header for a proof over field `field_id` at rate `log_inv_rate` answering
+    /// `num_test_queries` FRI queries
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProofHeader)` - Header with the current format version
+    /// * `Err(FriVeilError)` - A parameter does not fit its header field
+    pub fn new(
+        field_id: u8,
+        log_inv_rate: usize,
+        num_test_queries: usize,
+    ) -> Result<Self, FriVeilError> {
+        let log_inv_rate = u8::try_from(log_inv_rate).map_err(|_| {
+            FriVeilError::InvalidInput(format!("log_inv_rate {} too large", log_inv_rate))
+        })?;
+        let num_test_queries = u32::try_from(num_test_queries).map_err(|_| {
+            FriVeilError::InvalidInput(format!("num_test_queries {} too large", num_test_queries))
+        })?;
+        Ok(Self {
+            version: PROOF_FORMAT_VERSION,
+            field_id,
+            log_inv_rate,
+            num_test_queries,
+        })
+    }
+
+    /// Header a B128 proof generated under `fri_params` carries
+    pub fn for_params(fri_params: &FRIParams<B128>) -> Result<Self, FriVeilError> {
+        Self::new(
+            field_id::<B128>(),
+            fri_params.rs_code().log_inv_rate(),
+            fri_params.n_test_queries(),
+        )
+    }
+
+    /// Encode the header in its wire layout
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut bytes = [0u8; Self::ENCODED_LEN];
+        bytes[0] = self.version;
+        bytes[1] = self.field_id;
+        bytes[2] = self.log_inv_rate;
+        bytes[3..7].copy_from_slice(&self.num_test_queries.to_le_bytes());
+        bytes
+    }
+
+    /// Decode a header, rejecting unknown format versions
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProofHeader)` - Decoded header
+    /// * `Err(FriVeilError)` - Wrong length or unsupported version
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FriVeilError> {
+        let bytes: [u8; Self::ENCODED_LEN] = bytes.try_into().map_err(|_| {
+            FriVeilError::TranscriptTruncated(format!(
+                "Invalid proof header length: expected {} bytes, got {}",
+                Self::ENCODED_LEN,
+                bytes.len()
+            ))
+        })?;
+        if bytes[0] != PROOF_FORMAT_VERSION {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "proof format version",
+                expected: PROOF_FORMAT_VERSION as usize,
+                got: bytes[0] as usize,
+            });
+        }
+        Ok(Self {
+            version: bytes[0],
+            field_id: bytes[1],
+            log_inv_rate: bytes[2],
+            num_test_queries: u32::from_le_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        })
+    }
+
+    /// Check every header field against the verifier's B128 `fri_params`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The proof was generated with the same field and parameters
+    /// * `Err(FriVeilError)` - Field or parameter mismatch
+    pub fn check_against(&self, fri_params: &FRIParams<B128>) -> Result<(), FriVeilError> {
+        self.check_field_id(field_id::<B128>())?;
+        self.check_log_inv_rate(fri_params.rs_code().log_inv_rate())?;
+        self.check_num_test_queries(fri_params.n_test_queries())
+    }
+
+    /// Check the proof is over the field the verifier expects
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Field ids match
+    /// * `Err(FriVeilError::HeaderMismatch)` - Field mismatch naming both ids
+    pub fn check_field_id(&self, expected_field_id: u8) -> Result<(), FriVeilError> {
+        if self.field_id != expected_field_id {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "field_id",
+                expected: expected_field_id as usize,
+                got: self.field_id as usize,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the codeword was encoded at the rate the verifier expects
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rates match
+    /// * `Err(FriVeilError::HeaderMismatch)` - Mismatch naming both rates
+    pub fn check_log_inv_rate(&self, expected_log_inv_rate: usize) -> Result<(), FriVeilError> {
+        if self.log_inv_rate as usize != expected_log_inv_rate {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "log_inv_rate",
+                expected: expected_log_inv_rate,
+                got: self.log_inv_rate as usize,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the proof answers exactly the number of queries the verifier expects
+    ///
+    /// Both directions are rejected: more verifier queries would read past the proof's
+    /// query data, and fewer would silently verify at a lower security level than the
+    /// proof was generated for.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Query counts match
+    /// * `Err(FriVeilError::HeaderMismatch)` - Mismatch naming both counts
+    pub fn check_num_test_queries(&self, expected_queries: usize) -> Result<(), FriVeilError> {
+        if self.num_test_queries as usize != expected_queries {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "num_test_queries",
+                expected: expected_queries,
+                got: self.num_test_queries as usize,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proof_header_round_trip() {
+        let header = ProofHeader::new(field_id::<B128>(), 1, 128).expect("Failed to create header");
+        let decoded = ProofHeader::from_bytes(&header.to_bytes()).expect("Failed to decode");
+        assert_eq!(decoded, header);
+        assert_eq!(decoded.field_id, 7);
+        assert_eq!(decoded.log_inv_rate, 1);
+        assert!(decoded.check_num_test_queries(128).is_ok());
+        assert!(decoded.check_num_test_queries(64).is_err());
+    }
+
+    #[test]
+    fn test_proof_header_rejects_malformed_bytes() {
+        let mut bytes = ProofHeader::new(field_id::<B128>(), 1, 128)
+            .unwrap()
+            .to_bytes();
+        assert!(ProofHeader::from_bytes(&bytes[..4]).is_err());
+
+        bytes[0] = PROOF_FORMAT_VERSION + 1;
+        let err = ProofHeader::from_bytes(&bytes).expect_err("Unknown version should fail");
+        assert_eq!(
+            err,
+            FriVeilError::HeaderMismatch {
+                field: "proof format version",
+                expected: PROOF_FORMAT_VERSION as usize,
+                got: PROOF_FORMAT_VERSION as usize + 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_proof_header_field_mismatch() {
+        let header = ProofHeader::new(6, 1, 128).unwrap();
+        let err = header
+            .check_field_id(field_id::<B128>())
+            .expect_err("B64 header should be rejected by a B128 verifier");
+        assert_eq!(
+            err,
+            FriVeilError::HeaderMismatch {
+                field: "field_id",
+                expected: 7,
+                got: 6,
+            }
+        );
+    }
+}
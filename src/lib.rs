@@ -1,5 +1,15 @@
+pub mod audit;
+pub mod binding;
+pub mod bundle;
+pub mod cache;
+pub mod error;
 pub mod friveil;
+pub mod header;
 #[cfg(feature = "kzg")]
 pub mod kzg_proof_of_proof;
+pub mod mmr;
 pub mod poly;
+pub mod store;
+#[cfg(test)]
+mod test_utils;
 pub mod traits;
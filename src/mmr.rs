@@ -0,0 +1,295 @@
+//! Merkle mountain range over an append-only log
+//!
+//! A continuously growing DA log can't be recommitted with FRI on every append. An MMR
+//! keeps a forest of perfect binary trees ("mountains") whose sizes follow the binary
+//! representation of the leaf count, so appending touches only `O(log n)` nodes and
+//! historical entries keep their inclusion paths. Typical entries are the 32-byte roots
+//! returned by [`FriVeil::commit`](crate::friveil::FriVeil::commit), one per blob.
+//!
+//! # Hashing
+//!
+//! SHA-256 with one-byte domain separation:
+//!
+//! ```text
+//! leaf = H(0x00 || entry)
+//! node = H(0x01 || left || right)
+//! root = H(0x02 || leaf_count: u64 LE || peak_0 || ... || peak_k)
+//! ```
+//!
+//! Peaks are ordered left to right (tallest mountain first). Binding the leaf count into
+//! the root fixes the mountain layout a proof is checked against.
+
+use crate::error::FriVeilError;
+use sha2::{Digest, Sha256};
+
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+const ROOT_TAG: u8 = 0x02;
+
+fn hash_leaf(entry: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([LEAF_TAG])
+        .chain_update(entry)
+        .finalize()
+        .into()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([NODE_TAG])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+fn bag_peaks(leaf_count: u64, peaks: &[[u8; 32]]) -> [u8; 32] {
+    peaks
+        .iter()
+        .fold(
+            Sha256::new()
+                .chain_update([ROOT_TAG])
+                .chain_update(leaf_count.to_le_bytes()),
+            |hasher, peak| hasher.chain_update(peak),
+        )
+        .finalize()
+        .into()
+}
+
+/// Locate the mountain holding leaf `index` in an MMR of `leaf_count` leaves
+///
+/// Returns `(height, first_leaf, peak_position)`.
+fn find_mountain(leaf_count: u64, index: u64) -> Option<(usize, u64, usize)> {
+    let mut first_leaf = 0u64;
+    let mut peak_position = 0;
+    for height in (0..u64::BITS as usize).rev() {
+        if leaf_count & (1 << height) == 0 {
+            continue;
+        }
+        let size = 1u64 << height;
+        if index < first_leaf + size {
+            return Some((height, first_leaf, peak_position));
+        }
+        first_leaf += size;
+        peak_position += 1;
+    }
+    None
+}
+
+/// Inclusion proof for one MMR entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmrProof {
+    /// Number of leaves in the MMR the proof was generated against
+    pub leaf_count: u64,
+    /// Sibling hashes from the leaf up to its mountain's peak
+    pub siblings: Vec<[u8; 32]>,
+    /// All peaks of the MMR, left to right
+    pub peaks: Vec<[u8; 32]>,
+}
+
+/// Append-only Merkle mountain range committer
+#[derive(Clone, Debug, Default)]
+pub struct MmrCommitter {
+    /// `levels[h][i]` is the root of the `i`-th perfect subtree of height `h`
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MmrCommitter {
+    /// Create an empty MMR
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of appended entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.levels.first().map_or(0, Vec::len)
+    }
+
+    /// Whether no entries have been appended
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append an entry, returning its index
+    ///
+    /// Completes every mountain the new leaf closes, so the peaks stay in sync with the
+    /// binary representation of the leaf count.
+    pub fn append(&mut self, entry: &[u8]) -> usize {
+        let index = self.len();
+        let mut node = hash_leaf(entry);
+        let mut height = 0;
+        loop {
+            if self.levels.len() == height {
+                self.levels.push(Vec::new());
+            }
+            let level = &mut self.levels[height];
+            level.push(node);
+            if level.len() % 2 == 1 {
+                break;
+            }
+            node = hash_node(&level[level.len() - 2], &level[level.len() - 1]);
+            height += 1;
+        }
+        index
+    }
+
+    /// Peaks of the current mountains, tallest first
+    fn peaks(&self) -> Vec<[u8; 32]> {
+        self.levels
+            .iter()
+            .rev()
+            .filter(|level| level.len() % 2 == 1)
+            .map(|level| level[level.len() - 1])
+            .collect()
+    }
+
+    /// Current root, covering every appended entry
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        bag_peaks(self.len() as u64, &self.peaks())
+    }
+
+    /// Prove that the entry at `index` is part of the current root
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MmrProof)` - Path to the entry's peak plus all peaks
+    /// * `Err(FriVeilError)` - `index` is out of range
+    pub fn prove_inclusion(&self, index: usize) -> Result<MmrProof, FriVeilError> {
+        let leaf_count = self.len() as u64;
+        let (height, _, _) = find_mountain(leaf_count, index as u64).ok_or_else(|| {
+            FriVeilError::QueryOutOfRange(format!(
+                "Index {} out of range for MMR with {} entries",
+                index, leaf_count
+            ))
+        })?;
+
+        let siblings = (0..height)
+            .map(|level| self.levels[level][(index >> level) ^ 1])
+            .collect();
+
+        Ok(MmrProof {
+            leaf_count,
+            siblings,
+            peaks: self.peaks(),
+        })
+    }
+}
+
+/// Verify that `entry` is the `index`-th entry under an MMR `root`
+///
+/// # Returns
+///
+/// * `Ok(())` - The entry is included
+/// * `Err(FriVeilError)` - The proof is malformed or doesn't match `root`
+pub fn verify_inclusion(
+    root: [u8; 32],
+    index: usize,
+    entry: &[u8],
+    proof: &MmrProof,
+) -> Result<(), FriVeilError> {
+    let (height, first_leaf, peak_position) = find_mountain(proof.leaf_count, index as u64)
+        .ok_or_else(|| {
+            FriVeilError::QueryOutOfRange(format!(
+                "Index {} out of range for MMR with {} entries",
+                index, proof.leaf_count
+            ))
+        })?;
+    if proof.siblings.len() != height {
+        return Err(FriVeilError::InvalidInput(format!(
+            "Expected {} sibling hashes, got {}",
+            height,
+            proof.siblings.len()
+        )));
+    }
+    if proof.peaks.len() != proof.leaf_count.count_ones() as usize {
+        return Err(FriVeilError::InvalidInput(format!(
+            "Expected {} peaks, got {}",
+            proof.leaf_count.count_ones(),
+            proof.peaks.len()
+        )));
+    }
+
+    let offset = index as u64 - first_leaf;
+    let peak =
+        proof
+            .siblings
+            .iter()
+            .enumerate()
+            .fold(hash_leaf(entry), |node, (level, sibling)| {
+                if (offset >> level) & 1 == 0 {
+                    hash_node(&node, sibling)
+                } else {
+                    hash_node(sibling, &node)
+                }
+            });
+    if peak != proof.peaks[peak_position] {
+        return Err(FriVeilError::CommitmentMismatch(
+            "Inclusion path does not lead to the expected peak".to_string(),
+        ));
+    }
+    if bag_peaks(proof.leaf_count, &proof.peaks) != root {
+        return Err(FriVeilError::CommitmentMismatch(
+            "MMR peaks do not match the root".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(i: usize) -> Vec<u8> {
+        format!("blob-commitment-{}", i).into_bytes()
+    }
+
+    #[test]
+    fn test_mmr_append_changes_root() {
+        let mut mmr = MmrCommitter::new();
+        assert!(mmr.is_empty());
+
+        let mut roots = vec![mmr.root()];
+        for i in 0..5 {
+            assert_eq!(mmr.append(&entry(i)), i);
+            roots.push(mmr.root());
+        }
+        assert_eq!(mmr.len(), 5);
+
+        // Each append produces a new root, and 5 = 0b101 leaves form two mountains
+        roots.sort();
+        roots.dedup();
+        assert_eq!(roots.len(), 6);
+        assert_eq!(mmr.peaks().len(), 2);
+    }
+
+    #[test]
+    fn test_mmr_inclusion_proofs() {
+        let mut mmr = MmrCommitter::new();
+        for n in 1..=13 {
+            mmr.append(&entry(n - 1));
+            let root = mmr.root();
+            for index in 0..n {
+                let proof = mmr
+                    .prove_inclusion(index)
+                    .expect("Failed to prove inclusion");
+                assert!(verify_inclusion(root, index, &entry(index), &proof).is_ok());
+            }
+        }
+
+        let root = mmr.root();
+        let proof = mmr.prove_inclusion(6).unwrap();
+        assert!(verify_inclusion(root, 6, &entry(7), &proof).is_err());
+        assert!(verify_inclusion(root, 7, &entry(6), &proof).is_err());
+        assert!(mmr.prove_inclusion(13).is_err());
+
+        // A proof against an older root fails once the log has grown
+        let old_root = root;
+        mmr.append(&entry(13));
+        let proof = mmr.prove_inclusion(6).unwrap();
+        assert!(verify_inclusion(old_root, 6, &entry(6), &proof).is_err());
+    }
+}
@@ -1,14 +1,58 @@
+use crate::error::FriVeilError;
 use binius_field::{ExtensionField, PackedField};
 use binius_math::FieldBuffer;
-use binius_verifier::config::B1;
+use binius_verifier::config::{B1, B128};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use std::marker::PhantomData;
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    marker::PhantomData,
+    path::Path,
+};
 
 /// Number of bytes per field element (128 bits = 16 bytes)
-const BYTES_PER_ELEMENT: usize = 16;
-/// Number of bits per field element
-const BITS_PER_ELEMENT: usize = 128;
+pub(crate) const BYTES_PER_ELEMENT: usize = 16;
+
+/// Number of element variables of an MLE over `F` with `total_n_vars` variables
+fn element_log_len<F: ExtensionField<B1>>(total_n_vars: usize) -> Result<usize, FriVeilError> {
+    total_n_vars
+        .checked_sub(F::LOG_DEGREE)
+        .filter(|&n| n < usize::BITS as usize)
+        .ok_or_else(|| {
+            FriVeilError::InvalidLogLen(format!(
+                "total_n_vars {} must be between {} and {}",
+                total_n_vars,
+                F::LOG_DEGREE,
+                F::LOG_DEGREE + usize::BITS as usize - 1
+            ))
+        })
+}
+
+/// Expand `(element_index, value)` pairs into the dense element vector of an MLE with
+/// `total_n_vars` variables, leaving every other element zero
+pub(crate) fn sparse_to_dense_values<F>(
+    nonzero: &[(usize, F)],
+    total_n_vars: usize,
+) -> Result<Vec<F>, FriVeilError>
+where
+    F: ExtensionField<B1>,
+{
+    let dense_len = 1usize << element_log_len::<F>(total_n_vars)?;
+
+    let mut values = vec![F::ZERO; dense_len];
+    for &(index, value) in nonzero {
+        let slot = values.get_mut(index).ok_or_else(|| {
+            FriVeilError::QueryOutOfRange(format!(
+                "Element index {} out of range for {} elements",
+                index, dense_len
+            ))
+        })?;
+        *slot = value;
+    }
+    Ok(values)
+}
 
 /// Utility struct for converting bytes to packed multilinear extensions
 ///
@@ -46,6 +90,7 @@ where
     /// Create a new utility instance
     ///
     /// Initializes with the logarithm of the scalar field degree
+    #[must_use]
     pub fn new() -> Self {
         Self {
             log_scalar_bit_width: <P::Scalar as ExtensionField<B1>>::LOG_DEGREE,
@@ -53,6 +98,41 @@ where
         }
     }
 
+    /// Largest input, in bytes, that `bytes_to_packed_mle` accepts
+    ///
+    /// The Reed-Solomon evaluation domain used by `commit` is a binary subspace of the
+    /// scalar field, so its dimension is bounded by the field's bit width, with one
+    /// dimension reserved for the rate expansion. The padded element count must also be
+    /// addressable as a `usize`, whichever limit is smaller applies.
+    #[must_use]
+    pub fn max_data_bytes(&self) -> usize {
+        let field_max_log_len = (1usize << self.log_scalar_bit_width) - 1;
+        let usize_max_log_len = (usize::BITS - 1 - BYTES_PER_ELEMENT.ilog2()) as usize;
+        BYTES_PER_ELEMENT << field_max_log_len.min(usize_max_log_len)
+    }
+
+    /// Log length of the packed buffer needed to hold `num_bytes` of data
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - Log2 of the padded number of field elements
+    /// * `Err(FriVeilError::EmptyInput)` - `num_bytes` is zero
+    /// * `Err(FriVeilError::DataTooLarge)` - `num_bytes` exceeds [`Self::max_data_bytes`]
+    pub(crate) fn packed_log_len(&self, num_bytes: usize) -> Result<usize, FriVeilError> {
+        if num_bytes == 0 {
+            return Err(FriVeilError::EmptyInput);
+        }
+        let max_bytes = self.max_data_bytes();
+        if num_bytes > max_bytes {
+            return Err(FriVeilError::DataTooLarge {
+                got: num_bytes,
+                max: max_bytes,
+            });
+        }
+
+        let num_elements = num_bytes.div_ceil(BYTES_PER_ELEMENT);
+        Ok(num_elements.next_power_of_two().ilog2() as usize)
+    }
+
     /// Convert raw bytes to a packed multilinear extension
     ///
     /// # Process:
@@ -66,7 +146,9 @@ where
     ///
     /// # Returns
     /// * `Ok(PackedMLE)` - Successfully converted MLE
-    /// * `Err(String)` - Conversion error message
+    /// * `Err(FriVeilError::EmptyInput)` - `data` is empty
+    /// * `Err(FriVeilError::DataTooLarge)` - `data` is larger than [`Self::max_data_bytes`]
+    /// * `Err(FriVeilError)` - The field buffer could not be built
     ///
     /// # Example
     /// ```ignore
@@ -74,14 +156,9 @@ where
     /// let utils = Utils::<B128>::new();
     /// let mle = utils.bytes_to_packed_mle(&data)?;
     /// ```
-    pub fn bytes_to_packed_mle(&self, data: &[u8]) -> Result<PackedMLE<P>, String> {
-        // Calculate number of field elements needed
-        // Note: Using BITS_PER_ELEMENT here (not BYTES) to match the original logic
-        let num_elements = data.len().div_ceil(BITS_PER_ELEMENT);
-
+    pub fn bytes_to_packed_mle(&self, data: &[u8]) -> Result<PackedMLE<P>, FriVeilError> {
         // Pad to next power of 2 for MLE structure requirements
-        let padded_size = num_elements.next_power_of_two();
-        let big_field_n_vars = padded_size.ilog2() as usize;
+        let big_field_n_vars = self.packed_log_len(data.len())?;
         let packed_size = 1 << big_field_n_vars;
 
         // Convert bytes to field elements
@@ -102,6 +179,7 @@ where
         // Sequential version for non-parallel builds
         #[cfg(not(feature = "parallel"))]
         let mut packed_values: Vec<P::Scalar> = {
+            let num_elements = data.len().div_ceil(BYTES_PER_ELEMENT);
             let mut values = Vec::with_capacity(num_elements);
             for chunk in data.chunks(BYTES_PER_ELEMENT) {
                 let mut bytes_array = [0u8; BYTES_PER_ELEMENT];
@@ -117,8 +195,8 @@ where
 
         // Create FieldBuffer from scalar values
         // This provides efficient access patterns for polynomial operations
-        let packed_mle =
-            FieldBuffer::<P>::from_values(packed_values.as_slice()).map_err(|e| e.to_string())?;
+        let packed_mle = FieldBuffer::<P>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
 
         // Calculate total number of variables
         // This is the log of packed size plus the scalar bit width
@@ -131,4 +209,545 @@ where
             total_n_vars,
         })
     }
+
+    /// Convert a file to a packed MLE without first reading it into a byte buffer
+    ///
+    /// The file is streamed 16 bytes at a time straight into field elements, so only the
+    /// MLE itself is held in memory, rather than the file contents and the MLE as with
+    /// `bytes_to_packed_mle(&std::fs::read(path)?)`. The result is identical to that,
+    /// including the [`FriVeilError::EmptyInput`] error for an empty file.
+    ///
+    /// # Arguments
+    /// * `path` - File to convert
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - MLE over the file contents
+    /// * `Err(FriVeilError)` - I/O failure, an empty file, or a file larger than
+    ///   [`Self::max_data_bytes`]
+    pub fn file_to_packed_mle(&self, path: impl AsRef<Path>) -> Result<PackedMLE<P>, FriVeilError> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|e| FriVeilError::Io(format!("Failed to open {}: {}", path.display(), e)))?;
+        let file_len = file
+            .metadata()
+            .map_err(|e| FriVeilError::Io(format!("Failed to stat {}: {}", path.display(), e)))?
+            .len();
+        let file_len = usize::try_from(file_len).map_err(|_| {
+            FriVeilError::InvalidLogLen(format!("File of {} bytes is not addressable", file_len))
+        })?;
+        let packed_size = 1 << self.packed_log_len(file_len)?;
+
+        let mut reader = BufReader::new(file);
+        let mut packed_values = Vec::with_capacity(packed_size);
+        let mut remaining = file_len;
+        while remaining > 0 {
+            let chunk_len = remaining.min(BYTES_PER_ELEMENT);
+            let mut bytes_array = [0u8; BYTES_PER_ELEMENT];
+            reader
+                .read_exact(&mut bytes_array[..chunk_len])
+                .map_err(|e| {
+                    FriVeilError::Io(format!("Failed to read {}: {}", path.display(), e))
+                })?;
+            packed_values.push(P::Scalar::from(u128::from_le_bytes(bytes_array)));
+            remaining -= chunk_len;
+        }
+        packed_values.resize(packed_size, P::Scalar::zero());
+
+        let packed_mle = FieldBuffer::<P>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+        let total_n_vars = packed_mle.log_len() + self.log_scalar_bit_width;
+
+        Ok(PackedMLE::<P> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+
+    /// Evaluate a packed MLE at a point directly, without the equality polynomial
+    ///
+    /// Reference implementation for cross-checking `calculate_evaluation_claim`. It pins
+    /// the variable ordering: the MLE is over bits, with variables `0..log_scalar_bit_width`
+    /// selecting a bit of an element (least significant first, as `iter_bases` yields
+    /// them) and the remaining variables selecting the element. Each coordinate is folded
+    /// in turn, starting from variable 0:
+    /// ```text
+    /// v'[j] = v[2j] + r_i * (v[2j + 1] - v[2j])
+    /// ```
+    ///
+    /// # Arguments
+    /// * `packed_mle` - MLE to evaluate
+    /// * `point` - Evaluation point with `total_n_vars` coordinates
+    ///
+    /// # Returns
+    /// * `Ok(P::Scalar)` - The evaluation
+    /// * `Err(FriVeilError)` - The point has the wrong number of coordinates
+    pub fn mle_eval(
+        &self,
+        packed_mle: &PackedMLE<P>,
+        point: &[P::Scalar],
+    ) -> Result<P::Scalar, FriVeilError> {
+        if point.len() != packed_mle.total_n_vars {
+            return Err(FriVeilError::ParameterMismatch(format!(
+                "Evaluation point has {} coordinates but MLE has {} variables",
+                point.len(),
+                packed_mle.total_n_vars
+            )));
+        }
+
+        let mut evals: Vec<P::Scalar> = packed_mle
+            .packed_values
+            .iter()
+            .flat_map(|value| ExtensionField::<B1>::iter_bases(value))
+            .map(P::Scalar::from)
+            .collect();
+
+        for &r in point {
+            evals = evals
+                .chunks_exact(2)
+                .map(|pair| pair[0] + r * (pair[1] - pair[0]))
+                .collect();
+        }
+
+        Ok(evals[0])
+    }
+
+    /// Generate a deterministic random packed MLE with `total_n_vars` variables
+    ///
+    /// Intended for tests and benchmarks that need realistic, non-patterned data without
+    /// going through byte conversion. The same `seed` always yields the same MLE. This is
+    /// not a data ingestion path; use [`Self::bytes_to_packed_mle`] for real blobs.
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - Random MLE with `total_n_vars` variables
+    /// * `Err(FriVeilError)` - `total_n_vars` is too small to hold one field element
+    pub fn random_packed_mle(
+        &self,
+        total_n_vars: usize,
+        seed: u64,
+    ) -> Result<PackedMLE<P>, FriVeilError> {
+        let num_elements = 1usize << element_log_len::<P::Scalar>(total_n_vars)?;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let packed_values: Vec<P::Scalar> = (0..num_elements)
+            .map(|_| P::Scalar::from(rng.random::<u128>()))
+            .collect();
+
+        let packed_mle = FieldBuffer::<P>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+
+        Ok(PackedMLE::<P> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+
+    /// Build a packed MLE from the nonzero field elements of a mostly-zero blob
+    ///
+    /// Produces exactly what [`Self::bytes_to_packed_mle`] would for the equivalent dense
+    /// data padded to `2^(total_n_vars - log_scalar_bit_width)` elements, without walking
+    /// the zero bytes. If an index appears more than once, the last value wins.
+    ///
+    /// # Arguments
+    /// * `nonzero` - Pairs of `(element_index, value)`
+    /// * `total_n_vars` - Total number of variables of the MLE (including the bit variables)
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - MLE with `total_n_vars` variables
+    /// * `Err(FriVeilError)` - `total_n_vars` is too small, or an index is out of range
+    pub fn sparse_to_packed_mle(
+        &self,
+        nonzero: &[(usize, P::Scalar)],
+        total_n_vars: usize,
+    ) -> Result<PackedMLE<P>, FriVeilError> {
+        let packed_values = sparse_to_dense_values(nonzero, total_n_vars)?;
+        let packed_mle = FieldBuffer::<P>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+
+        Ok(PackedMLE::<P> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+}
+
+/// Format version written as the first byte of [`PackedMLE::to_bytes`]
+pub const PACKED_MLE_FORMAT_VERSION: u8 = 1;
+
+/// Bytes of the little-endian length prefix written by [`Utils::text_to_packed_mle`]
+const TEXT_LEN_PREFIX_BYTES: usize = 8;
+
+impl Utils<B128> {
+    /// Concatenate independently packed segments into one MLE
+    ///
+    /// Element values are joined in order and zero-padded to the next power of two, so
+    /// when every segment's bytes fill its MLE exactly, the result equals
+    /// `bytes_to_packed_mle` on the concatenated bytes. Each segment must start at an
+    /// offset that is a multiple of its own length (e.g. segments in non-increasing size
+    /// order), which keeps it a sub-cube of the combined MLE selected by fixing the high
+    /// element variables.
+    ///
+    /// # Arguments
+    /// * `segments` - Packed segments, in data order
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - Combined MLE
+    /// * `Err(FriVeilError)` - No segments, a misaligned segment, or a combined size above
+    ///   [`Self::max_data_bytes`]
+    pub fn concat_packed_mles(
+        &self,
+        segments: &[PackedMLE<B128>],
+    ) -> Result<PackedMLE<B128>, FriVeilError> {
+        if segments.is_empty() {
+            return Err(FriVeilError::InvalidInput(
+                "Cannot concatenate zero segments".to_string(),
+            ));
+        }
+
+        let mut packed_values = Vec::new();
+        for (index, segment) in segments.iter().enumerate() {
+            let offset = packed_values.len();
+            if offset % segment.packed_values.len() != 0 {
+                return Err(FriVeilError::InvalidInput(format!(
+                    "Segment {} of {} elements starts at misaligned offset {}",
+                    index,
+                    segment.packed_values.len(),
+                    offset
+                )));
+            }
+            packed_values.extend_from_slice(&segment.packed_values);
+        }
+
+        let packed_size = 1 << self.packed_log_len(packed_values.len() * BYTES_PER_ELEMENT)?;
+        packed_values.resize(packed_size, B128::zero());
+        let packed_mle = FieldBuffer::<B128>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+        let total_n_vars = packed_mle.log_len() + self.log_scalar_bit_width;
+
+        Ok(PackedMLE::<B128> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+
+    /// Number of elements in the slot holding one record of `record_size` bytes
+    ///
+    /// The record's element count rounded up to a power of two, so consecutive slots
+    /// start on slot-aligned element indices and each record is a sub-cube of the MLE.
+    #[must_use]
+    pub fn record_slot_elements(record_size: usize) -> usize {
+        record_size.div_ceil(BYTES_PER_ELEMENT).next_power_of_two()
+    }
+
+    /// Pack fixed-size records into an MLE with one aligned slot per record
+    ///
+    /// Record `i` fills elements `i * slot..(i + 1) * slot`, with `slot` given by
+    /// [`Self::record_slot_elements`] and the rest of the slot zeroed, so no record shares
+    /// an element or straddles a slot boundary and each one can be opened on its own
+    /// with `FriVeilDefault::prove_record_inclusion`. When `N` is a power-of-two multiple
+    /// of 16 bytes no padding is inserted, and the result equals `bytes_to_packed_mle` on
+    /// the concatenated records.
+    ///
+    /// # Arguments
+    /// * `records` - Records in log order
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - MLE over the record slots
+    /// * `Err(FriVeilError)` - No records, empty records, or too much data
+    pub fn records_to_packed_mle<const N: usize>(
+        &self,
+        records: impl IntoIterator<Item = [u8; N]>,
+    ) -> Result<PackedMLE<B128>, FriVeilError> {
+        if N == 0 {
+            return Err(FriVeilError::InvalidInput(
+                "Records must not be empty".to_string(),
+            ));
+        }
+        let slot = Self::record_slot_elements(N);
+
+        let mut packed_values = Vec::new();
+        for record in records {
+            let slot_start = packed_values.len();
+            packed_values.extend(record.chunks(BYTES_PER_ELEMENT).map(|chunk| {
+                let mut bytes_array = [0u8; BYTES_PER_ELEMENT];
+                bytes_array[..chunk.len()].copy_from_slice(chunk);
+                B128::from(u128::from_le_bytes(bytes_array))
+            }));
+            packed_values.resize(slot_start + slot, B128::zero());
+        }
+        if packed_values.is_empty() {
+            return Err(FriVeilError::InvalidInput(
+                "Cannot pack zero records".to_string(),
+            ));
+        }
+
+        let packed_size = 1 << self.packed_log_len(packed_values.len() * BYTES_PER_ELEMENT)?;
+        packed_values.resize(packed_size, B128::zero());
+        let packed_mle = FieldBuffer::<B128>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+        let total_n_vars = packed_mle.log_len() + self.log_scalar_bit_width;
+
+        Ok(PackedMLE::<B128> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+
+    /// Convert UTF-8 text to a packed MLE with length-prefixed framing
+    ///
+    /// The string's byte length is written as a `u64` little-endian prefix ahead of its
+    /// UTF-8 bytes, and the result is converted with `bytes_to_packed_mle`. The prefix
+    /// lets [`Self::packed_mle_to_text`] strip the zero padding and recover the exact
+    /// string, including trailing NUL characters and the empty string.
+    ///
+    /// # Arguments
+    /// * `text` - Text to convert
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - MLE over the framed bytes
+    /// * `Err(FriVeilError)` - The framed text exceeds [`Self::max_data_bytes`]
+    pub fn text_to_packed_mle(&self, text: &str) -> Result<PackedMLE<B128>, FriVeilError> {
+        let mut framed = Vec::with_capacity(TEXT_LEN_PREFIX_BYTES + text.len());
+        framed.extend_from_slice(&(text.len() as u64).to_le_bytes());
+        framed.extend_from_slice(text.as_bytes());
+        self.bytes_to_packed_mle(&framed)
+    }
+
+    /// Recover the text framed by [`Self::text_to_packed_mle`]
+    ///
+    /// # Arguments
+    /// * `packed_mle` - MLE produced by `text_to_packed_mle`
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The original text
+    /// * `Err(FriVeilError)` - The length prefix exceeds the MLE, or the bytes are not UTF-8
+    pub fn packed_mle_to_text(&self, packed_mle: &PackedMLE<B128>) -> Result<String, FriVeilError> {
+        let bytes: Vec<u8> = packed_mle
+            .packed_values
+            .iter()
+            .flat_map(|&value| u128::from(value).to_le_bytes())
+            .collect();
+        let (prefix, body) = bytes
+            .split_at_checked(TEXT_LEN_PREFIX_BYTES)
+            .ok_or_else(|| {
+                FriVeilError::InvalidInput(
+                    "MLE is too short to hold a text length prefix".to_string(),
+                )
+            })?;
+        let len = u64::from_le_bytes(prefix.try_into().expect("prefix is 8 bytes"));
+        let text = usize::try_from(len)
+            .ok()
+            .and_then(|len| body.get(..len))
+            .ok_or_else(|| {
+                FriVeilError::InvalidInput(format!(
+                    "Text length prefix {} exceeds the {} bytes held by the MLE",
+                    len,
+                    body.len()
+                ))
+            })?;
+        String::from_utf8(text.to_vec()).map_err(|e| FriVeilError::FieldDecode(e.to_string()))
+    }
+
+    /// Recover the bytes packed by `bytes_to_packed_mle`
+    ///
+    /// The MLE only stores the zero-padded elements, so trailing zero bytes of the input
+    /// and padding look the same. The original length therefore travels alongside the
+    /// MLE, e.g. as the `data_len` of a [`ProofBinding`](crate::binding::ProofBinding),
+    /// and everything past it must be zero.
+    ///
+    /// # Arguments
+    /// * `packed_mle` - MLE produced by `bytes_to_packed_mle`
+    /// * `data_len` - Byte length of the original input
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The original bytes
+    /// * `Err(FriVeilError)` - `data_len` exceeds the MLE, or nonzero bytes follow it
+    pub fn packed_mle_to_bytes(
+        &self,
+        packed_mle: &PackedMLE<B128>,
+        data_len: usize,
+    ) -> Result<Vec<u8>, FriVeilError> {
+        let mut bytes: Vec<u8> = packed_mle
+            .packed_values
+            .iter()
+            .flat_map(|&value| u128::from(value).to_le_bytes())
+            .collect();
+        if data_len > bytes.len() {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Data length {} exceeds the {} bytes held by the MLE",
+                data_len,
+                bytes.len()
+            )));
+        }
+        if bytes[data_len..].iter().any(|&byte| byte != 0) {
+            return Err(FriVeilError::InvalidInput(format!(
+                "Nonzero bytes follow data length {}: the MLE holds more data",
+                data_len
+            )));
+        }
+        bytes.truncate(data_len);
+        Ok(bytes)
+    }
+}
+
+impl<P> PackedMLE<P>
+where
+    P: PackedField + ExtensionField<B1>,
+    P::Scalar: From<u128> + ExtensionField<B1>,
+{
+    /// Extract the sub-polynomial obtained by fixing some variables to boolean values
+    ///
+    /// # Variable indexing
+    /// Variables are numbered as in the full evaluation point of length `total_n_vars`:
+    /// - `0..log_scalar_bit_width` select a bit inside a single field element
+    /// - `log_scalar_bit_width..total_n_vars` select the field element, least significant first
+    ///
+    /// Only element variables can be fixed, and only to `0` or `1`, so the result is again a
+    /// packed MLE over whole field elements (e.g. a row of a 2D layout) that can be
+    /// committed to independently. The remaining variables keep their relative order.
+    ///
+    /// # Arguments
+    /// * `fixed` - Pairs of `(variable_index, value)` with `value` either zero or one
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - MLE over the remaining `total_n_vars - fixed.len()` variables
+    /// * `Err(FriVeilError)` - A variable index is out of range, duplicated, or not boolean-fixable
+    pub fn subpoly(&self, fixed: &[(usize, P::Scalar)]) -> Result<PackedMLE<P>, FriVeilError> {
+        let log_scalar_bit_width = <P::Scalar as ExtensionField<B1>>::LOG_DEGREE;
+        let big_field_n_vars = self.total_n_vars - log_scalar_bit_width;
+
+        // Bit masks over the element index selecting fixed variables and their values
+        let mut fixed_mask = 0usize;
+        let mut fixed_bits = 0usize;
+        for &(var, value) in fixed {
+            if var < log_scalar_bit_width || var >= self.total_n_vars {
+                return Err(FriVeilError::QueryOutOfRange(format!(
+                    "Variable {} cannot be fixed: only element variables {}..{} are supported",
+                    var, log_scalar_bit_width, self.total_n_vars
+                )));
+            }
+            let bit = 1 << (var - log_scalar_bit_width);
+            if fixed_mask & bit != 0 {
+                return Err(FriVeilError::InvalidInput(format!(
+                    "Variable {} is fixed more than once",
+                    var
+                )));
+            }
+            fixed_mask |= bit;
+            if value == P::Scalar::ONE {
+                fixed_bits |= bit;
+            } else if value != P::Scalar::ZERO {
+                return Err(FriVeilError::InvalidInput(format!(
+                    "Variable {} must be fixed to zero or one",
+                    var
+                )));
+            }
+        }
+
+        let free_positions: Vec<usize> = (0..big_field_n_vars)
+            .filter(|pos| fixed_mask & (1 << pos) == 0)
+            .collect();
+
+        // Scatter each index of the sub-polynomial into the free positions of the full index
+        let packed_values: Vec<P::Scalar> = (0..1usize << free_positions.len())
+            .map(|sub_index| {
+                let full_index = free_positions
+                    .iter()
+                    .enumerate()
+                    .fold(fixed_bits, |acc, (bit, &pos)| {
+                        acc | (((sub_index >> bit) & 1) << pos)
+                    });
+                self.packed_values[full_index]
+            })
+            .collect();
+
+        let packed_mle = FieldBuffer::<P>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+        let total_n_vars = packed_mle.log_len() + log_scalar_bit_width;
+
+        Ok(PackedMLE::<P> {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
+}
+
+impl PackedMLE<B128> {
+    /// Serialize the MLE so it can be cached and reloaded without reconversion
+    ///
+    /// # Layout
+    ///
+    /// ```text
+    /// | version: u8 | total_n_vars: u8 | element_0: u128 LE | ... | element_n: u128 LE |
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.packed_values.len() * BYTES_PER_ELEMENT);
+        bytes.push(PACKED_MLE_FORMAT_VERSION);
+        bytes.push(self.total_n_vars as u8);
+        for &value in &self.packed_values {
+            bytes.extend_from_slice(&u128::from(value).to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Reload an MLE written by [`Self::to_bytes`]
+    ///
+    /// The result commits to the same root as the MLE that was serialized.
+    ///
+    /// # Arguments
+    /// * `bytes` - Serialized MLE
+    ///
+    /// # Returns
+    /// * `Ok(PackedMLE)` - The cached MLE
+    /// * `Err(FriVeilError)` - Unsupported version, a `total_n_vars` too large to address,
+    ///   or a length that does not match `total_n_vars`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FriVeilError> {
+        let [version, total_n_vars, values @ ..] = bytes else {
+            return Err(FriVeilError::FieldDecode(
+                "Packed MLE bytes are too short for the header".to_string(),
+            ));
+        };
+        if *version != PACKED_MLE_FORMAT_VERSION {
+            return Err(FriVeilError::HeaderMismatch {
+                field: "packed MLE format version",
+                expected: PACKED_MLE_FORMAT_VERSION as usize,
+                got: *version as usize,
+            });
+        }
+        let total_n_vars = *total_n_vars as usize;
+        let values_len = (1usize << element_log_len::<B128>(total_n_vars)?)
+            .checked_mul(BYTES_PER_ELEMENT)
+            .ok_or_else(|| {
+                FriVeilError::InvalidLogLen(format!(
+                    "Packed MLE with {} variables is too large to address",
+                    total_n_vars
+                ))
+            })?;
+        if values.len() != values_len {
+            return Err(FriVeilError::FieldDecode(format!(
+                "Packed MLE with {} variables needs {} bytes of elements, got {}",
+                total_n_vars,
+                values_len,
+                values.len()
+            )));
+        }
+
+        let packed_values: Vec<B128> = values
+            .chunks_exact(BYTES_PER_ELEMENT)
+            .map(|chunk| B128::from(u128::from_le_bytes(chunk.try_into().expect("16 bytes"))))
+            .collect();
+        let packed_mle = FieldBuffer::<B128>::from_values(packed_values.as_slice())
+            .map_err(FriVeilError::binius)?;
+
+        Ok(Self {
+            packed_mle,
+            packed_values,
+            total_n_vars,
+        })
+    }
 }
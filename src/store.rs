@@ -0,0 +1,79 @@
+//! Pluggable storage for commitments, looked up by root
+//!
+//! A prover serving many blobs doesn't have to keep every [`CommitOutput`] in hand: it
+//! can put them in a [`CommitmentStore`] after committing and fetch them by root when a
+//! proof is requested, see `FriVeilDefault::prove_from_store`. Where the outputs live
+//! (memory, disk, a remote service) is up to the implementation;
+//! [`InMemoryCommitmentStore`] is the default.
+
+use binius_prover::{
+    fri::CommitOutput,
+    hash::parallel_compression::ParallelCompressionAdaptor,
+    merkle_tree::{MerkleTreeProver, prover::BinaryMerkleTreeProver},
+};
+use binius_verifier::{
+    config::B128,
+    hash::{StdCompression, StdDigest},
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Commit output of `FriVeil::commit` over packed fields `P`, as kept in a store
+///
+/// This is the one spelling of the binius `CommitOutput` with the crate's SHA-256 Merkle
+/// tree; `P` defaults to the `B128` of [`FriVeilDefault`](crate::friveil::FriVeilDefault).
+pub type StoredCommitOutput<P = B128> = CommitOutput<
+    P,
+    Vec<u8>,
+    <BinaryMerkleTreeProver<B128, StdDigest, ParallelCompressionAdaptor<StdCompression>> as MerkleTreeProver<B128>>::Committed,
+>;
+
+/// Backend holding commit outputs by their commitment root
+///
+/// Outputs are handed out as `Arc`s, so an in-memory backend shares them instead of
+/// copying the codeword and Merkle tree, and other backends can decode into one.
+pub trait CommitmentStore {
+    /// Commit output whose commitment is `root`, if the store holds one
+    fn get(&self, root: &[u8]) -> Option<Arc<StoredCommitOutput>>;
+
+    /// Store `commit_output` under its commitment root, replacing any previous entry
+    fn insert(&mut self, commit_output: StoredCommitOutput) -> Arc<StoredCommitOutput>;
+}
+
+/// [`CommitmentStore`] keeping every output in a `HashMap`, without eviction
+#[derive(Default)]
+pub struct InMemoryCommitmentStore {
+    entries: HashMap<Vec<u8>, Arc<StoredCommitOutput>>,
+}
+
+impl InMemoryCommitmentStore {
+    /// Create an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored commitments
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the store holds no commitments
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl CommitmentStore for InMemoryCommitmentStore {
+    fn get(&self, root: &[u8]) -> Option<Arc<StoredCommitOutput>> {
+        self.entries.get(root).cloned()
+    }
+
+    fn insert(&mut self, commit_output: StoredCommitOutput) -> Arc<StoredCommitOutput> {
+        let commit_output = Arc::new(commit_output);
+        self.entries
+            .insert(commit_output.commitment.clone(), Arc::clone(&commit_output));
+        commit_output
+    }
+}
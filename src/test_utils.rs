@@ -0,0 +1,102 @@
+//! Fixtures shared by the unit tests of several modules
+
+use crate::{
+    binding::ProofBinding,
+    friveil::{B128, FriVeilDefault},
+    poly::{PackedMLE, Utils},
+    store::StoredCommitOutput,
+    traits::FriVeilSampling,
+};
+use binius_math::ntt::{NeighborsLastMultiThread, domain_context::GenericPreExpanded};
+use binius_transcript::VerifierTranscript;
+use binius_verifier::{config::StdChallenger, fri::FRIParams};
+
+/// `size_bytes` bytes counting up from zero, wrapping at 256
+pub(crate) fn create_test_data(size_bytes: usize) -> Vec<u8> {
+    (0..size_bytes).map(|i| (i % 256) as u8).collect()
+}
+
+/// Committed test data together with everything needed to prove and verify it
+pub(crate) struct ProofFixture {
+    pub(crate) friveil: FriVeilDefault,
+    pub(crate) packed_mle_values: PackedMLE<B128>,
+    pub(crate) fri_params: FRIParams<B128>,
+    pub(crate) ntt: NeighborsLastMultiThread<GenericPreExpanded<B128>>,
+    pub(crate) commit_output: StoredCommitOutput,
+    pub(crate) evaluation_point: Vec<B128>,
+    pub(crate) evaluation_claim: B128,
+}
+
+impl ProofFixture {
+    /// Commit to [`create_test_data`] of `size_bytes` at rate 1/2 with 3 test queries
+    pub(crate) fn new(size_bytes: usize) -> Self {
+        Self::with_log_inv_rate(size_bytes, 1)
+    }
+
+    /// Same as [`Self::new`] at rate `2^-log_inv_rate`
+    pub(crate) fn with_log_inv_rate(size_bytes: usize, log_inv_rate: usize) -> Self {
+        let packed_mle_values = Utils::<B128>::new()
+            .bytes_to_packed_mle(&create_test_data(size_bytes))
+            .expect("Failed to create packed MLE");
+        let friveil = FriVeilDefault::new(log_inv_rate, 3, packed_mle_values.total_n_vars, 3);
+        let (fri_params, ntt) = friveil
+            .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+            .expect("Failed to initialize FRI context");
+        let commit_output = friveil
+            .commit(
+                packed_mle_values.packed_mle.clone(),
+                fri_params.clone(),
+                &ntt,
+            )
+            .expect("Failed to commit");
+        let evaluation_point = friveil
+            .calculate_evaluation_point_from_seed([0; 32])
+            .expect("Failed to generate evaluation point");
+        let evaluation_claim = friveil
+            .calculate_evaluation_claim(&packed_mle_values.packed_values, &evaluation_point)
+            .expect("Failed to calculate evaluation claim");
+
+        Self {
+            friveil,
+            packed_mle_values,
+            fri_params,
+            ntt,
+            commit_output,
+            evaluation_point,
+            evaluation_claim,
+        }
+    }
+
+    pub(crate) fn prove(&self) -> VerifierTranscript<StdChallenger> {
+        self.friveil
+            .prove(
+                self.packed_mle_values.packed_mle.clone(),
+                self.fri_params.clone(),
+                &self.ntt,
+                &self.commit_output,
+                &self.evaluation_point,
+            )
+            .expect("Failed to generate proof")
+    }
+
+    pub(crate) fn prove_bound(&self, binding: &ProofBinding) -> VerifierTranscript<StdChallenger> {
+        self.friveil
+            .prove_bound(
+                self.packed_mle_values.packed_mle.clone(),
+                self.fri_params.clone(),
+                &self.ntt,
+                &self.commit_output,
+                &self.evaluation_point,
+                binding,
+            )
+            .expect("Failed to generate bound proof")
+    }
+
+    pub(crate) fn root(&self) -> [u8; 32] {
+        self.commit_output
+            .commitment
+            .clone()
+            .try_into()
+            .expect("We know commitment size is 32 bytes")
+    }
+}
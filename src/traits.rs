@@ -1,7 +1,9 @@
+use crate::{error::FriVeilError, friveil::Commitment};
 use binius_field::PackedExtension;
 pub use binius_field::PackedField;
 use binius_math::ntt::{AdditiveNTT, NeighborsLastMultiThread, domain_context::GenericPreExpanded};
 use binius_prover::{
+    fri::CommitOutput,
     hash::parallel_compression::ParallelCompressionAdaptor,
     merkle_tree::{MerkleTreeProver, prover::BinaryMerkleTreeProver},
 };
@@ -12,7 +14,7 @@ use binius_verifier::{
     fri::FRIParams,
     hash::{StdCompression, StdDigest},
 };
-use std::mem::MaybeUninit;
+use std::{io::Read, mem::MaybeUninit};
 
 pub trait FriVeilSampling<
     P: PackedField<Scalar = B128> + PackedExtension<B128> + PackedExtension<B1>,
@@ -23,14 +25,51 @@ pub trait FriVeilSampling<
         &self,
         corrupted_codeword: &mut [P::Scalar],
         corrupted_indices: &[usize],
-    ) -> Result<(), String>;
+    ) -> Result<(), FriVeilError>;
     fn verify_evaluation(
         &self,
         verifier_transcript: &mut VerifierTranscript<StdChallenger>,
         evaluation_claim: P::Scalar,
         evaluation_point: &[P::Scalar],
         fri_params: &FRIParams<P::Scalar>,
-    ) -> Result<(), String>;
+    ) -> Result<(), FriVeilError>;
+
+    fn verify_evaluation_with_root(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        commitment: [u8; 32],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError>;
+
+    fn verify_evaluation_with_root_hex(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        root_hex: &str,
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError>;
+
+    fn verify_evaluation_with_cid(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        cid: &[u8],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError>;
+
+    fn verify_evaluation_with_nonce(
+        &self,
+        verifier_transcript: &mut VerifierTranscript<StdChallenger>,
+        nonced_root: [u8; 32],
+        nonce: &[u8; 32],
+        evaluation_claim: P::Scalar,
+        evaluation_point: &[P::Scalar],
+        fri_params: &FRIParams<P::Scalar>,
+    ) -> Result<(), FriVeilError>;
 
     fn verify_inclusion_proof(
         &self,
@@ -39,7 +78,7 @@ pub trait FriVeilSampling<
         index: usize,
         fri_params: &FRIParams<P::Scalar>,
         commitment: [u8; 32],
-    ) -> Result<(), String>;
+    ) -> Result<(), FriVeilError>;
 
     fn inclusion_proof(
         &self,
@@ -49,19 +88,19 @@ pub trait FriVeilSampling<
             ParallelCompressionAdaptor<StdCompression>,
         > as MerkleTreeProver<P::Scalar>>::Committed,
         index: usize,
-    ) -> Result<VerifierTranscript<StdChallenger>, String>;
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError>;
 
     fn decode_codeword(
         &self,
         codeword: &[P::Scalar],
         fri_params: FRIParams<P::Scalar>,
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
-    ) -> Result<Vec<P::Scalar>, String>;
+    ) -> Result<Vec<P::Scalar>, FriVeilError>;
 
     fn extract_commitment(
         &self,
         verifier_transcript: &mut VerifierTranscript<StdChallenger>,
-    ) -> Result<Vec<u8>, String>;
+    ) -> Result<Vec<u8>, FriVeilError>;
 
     fn decode_batch(
         &self,
@@ -71,7 +110,7 @@ pub trait FriVeilSampling<
         ntt: &NeighborsLastMultiThread<GenericPreExpanded<P::Scalar>>,
         data: &[P::Scalar],
         output: &mut [MaybeUninit<P::Scalar>],
-    ) -> Result<(), String>;
+    ) -> Result<(), FriVeilError>;
 }
 
 pub trait FriVeilUtils {
@@ -80,4 +119,35 @@ pub trait FriVeilUtils {
         &self,
         bytes: Vec<u8>,
     ) -> VerifierTranscript<StdChallenger>;
+    fn reconstruct_transcript_bounded(
+        &self,
+        bytes: &[u8],
+        max_proof_bytes: usize,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError>;
+    fn reconstruct_transcript_from_reader<R: Read>(
+        &self,
+        reader: R,
+        fri_params: &FRIParams<B128>,
+    ) -> Result<VerifierTranscript<StdChallenger>, FriVeilError>;
+    fn roots_equal_ct(&self, a: &[u8], b: &[u8]) -> bool;
+    fn bundle<P, C>(
+        &self,
+        commit_output: &CommitOutput<P, Vec<u8>, C>,
+        proof: &VerifierTranscript<StdChallenger>,
+        fri_params: &FRIParams<B128>,
+    ) -> Result<Vec<u8>, FriVeilError>;
+    fn unbundle(
+        &self,
+        bytes: &[u8],
+        fri_params: &FRIParams<B128>,
+    ) -> Result<([u8; 32], VerifierTranscript<StdChallenger>), FriVeilError>;
+}
+
+/// Extension methods on the binius `CommitOutput` returned by `FriVeil::commit`
+pub trait CommitOutputExt {
+    fn memory_footprint(&self, fri_params: &FRIParams<B128>) -> usize;
+    fn root(&self) -> Result<[u8; 32], FriVeilError>;
+    fn cid(&self) -> Vec<u8>;
+    fn nonced_root(&self, nonce: &[u8; 32]) -> [u8; 32];
+    fn prune(self) -> Result<Commitment, FriVeilError>;
 }
@@ -0,0 +1,89 @@
+use frivail::{
+    friveil::{B128, FriVeilDefault},
+    poly::Utils,
+};
+use std::{fmt::Write, fs, path::PathBuf};
+
+/// Set to regenerate `testdata/golden_proof.txt` after an intentional format change
+const UPDATE_ENV: &str = "FRIVEIL_UPDATE_GOLDEN";
+
+fn golden_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata/golden_proof.txt")
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+/// Commit and prove over fixed inputs, returning the golden file contents
+fn generate_golden() -> String {
+    let data: Vec<u8> = (0..1024).map(|i| (i % 256) as u8).collect();
+    let packed_mle_values = Utils::<B128>::new()
+        .bytes_to_packed_mle(&data)
+        .expect("Failed to create packed MLE");
+    let friveil = FriVeilDefault::new(1, 3, packed_mle_values.total_n_vars, 3);
+    let (fri_params, ntt) = friveil
+        .initialize_fri_context(packed_mle_values.packed_mle.log_len())
+        .expect("Failed to initialize FRI context");
+    let commit_output = friveil
+        .commit(
+            packed_mle_values.packed_mle.clone(),
+            fri_params.clone(),
+            &ntt,
+        )
+        .expect("Failed to commit");
+    let evaluation_point = friveil
+        .calculate_evaluation_point_from_seed([0; 32])
+        .expect("Failed to generate evaluation point");
+    let proof = friveil
+        .prove_to_bytes(
+            packed_mle_values.packed_mle,
+            fri_params,
+            &ntt,
+            &commit_output,
+            &evaluation_point,
+        )
+        .expect("Failed to generate proof");
+
+    format!(
+        "root={}\nproof={}\n",
+        to_hex(&commit_output.commitment),
+        to_hex(&proof)
+    )
+}
+
+#[test]
+fn test_golden_proof_vector() {
+    let generated = generate_golden();
+    let path = golden_path();
+
+    if std::env::var_os(UPDATE_ENV).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).expect("Failed to create testdata dir");
+        fs::write(&path, &generated).expect("Failed to write golden file");
+        eprintln!("Wrote golden test vector to {}", path.display());
+        return;
+    }
+
+    // A missing vector is a failure, not a pass: otherwise a clean checkout guards nothing
+    let expected = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to read golden file {}: {}. Generate it with {}=1 and commit it",
+            path.display(),
+            e,
+            UPDATE_ENV
+        )
+    });
+    for (expected_line, generated_line) in expected.lines().zip(generated.lines()) {
+        let key = expected_line.split('=').next().unwrap_or_default();
+        assert_eq!(
+            generated_line, expected_line,
+            "Golden `{}` changed. If the proof format or commitment scheme change is \
+             intentional, rerun with {}=1 and commit testdata/golden_proof.txt",
+            key, UPDATE_ENV
+        );
+    }
+    assert_eq!(expected.lines().count(), generated.lines().count());
+}
@@ -30,7 +30,7 @@ fn test_integration_main() {
     const LOG_INV_RATE: usize = 1;
     // Security parameter: number of queries to perform in the FRI protocol
     const NUM_TEST_QUERIES: usize = 128;
-    const DATA_SIZE_KB: usize = 9;
+    const DATA_SIZE_KB: usize = 1;
 
     info!("🚀 Starting Binius Data Availability Sampling Scheme");
     info!("📋 Configuration:");
@@ -81,8 +81,10 @@ fn test_integration_main() {
     info!("✅ FRIVeil context initialized in {} ms", init_time);
 
     let start = Instant::now();
-    info!("🎲 Generating random evaluation point");
-    let evaluation_point = friveil.calculate_evaluation_point_random().unwrap();
+    info!("🎲 Generating evaluation point");
+    let evaluation_point = friveil
+        .calculate_evaluation_point_from_seed([0; 32])
+        .unwrap();
     let eval_time = start.elapsed().as_millis();
     info!("✅ Evaluation point generated in {} ms", eval_time);
     info!(
@@ -346,8 +348,8 @@ fn test_integration_main() {
     let _span = span!(Level::INFO, "proof_generation").entered();
     info!("📝 Phase 9: Generating evaluation proof");
     let start = Instant::now();
-    let mut verifier_transcript = friveil
-        .prove(
+    let transcript_bytes = friveil
+        .prove_to_bytes(
             packed_mle_values.packed_mle.clone(),
             fri_params.clone(),
             &ntt,
@@ -376,17 +378,13 @@ fn test_integration_main() {
     let _span = span!(Level::INFO, "final_verification").entered();
     info!("🔍 Phase 10: Final proof verification");
 
-    // Extract transcript bytes for network propagation
-    let transcript_bytes = friveil.get_transcript_bytes(&verifier_transcript);
     info!(
         "📦 Transcript size: {} bytes (ready for network transmission)",
         transcript_bytes.len()
     );
 
-    // Example: On the receiving network node, you would do:
-    // let mut reconstructed_transcript = reconstruct_transcript_from_bytes(transcript_bytes);
-    // Then use it for verification:
-    // friveil.verify_evaluation(&mut reconstructed_transcript, evaluation_claim, &evaluation_point, &fri_params)?;
+    // On the receiving network node, rebuild the transcript from the propagated bytes
+    let mut verifier_transcript = friveil.reconstruct_transcript_from_bytes(transcript_bytes);
 
     let start = Instant::now();
     let result = friveil.verify_evaluation(